@@ -0,0 +1,225 @@
+use std::{collections::HashSet, ops::Range};
+
+const PATTERN_KEYWORDS: [&str; 4] = ["mut", "ref", "in", "_"];
+const VALUE_KEYWORDS: [&str; 5] = ["self", "true", "false", "crate", "super"];
+
+fn is_word_character(character: char) -> bool {
+    character.is_alphanumeric() || character == '_'
+}
+
+fn raw_str_end(code: &str) -> Option<usize> {
+    let hashes = code[1..].len() - code[1..].trim_start_matches('#').len();
+    let body_start = 1 + hashes;
+
+    if !code[body_start..].starts_with('"') {
+        return None;
+    }
+
+    let closing = format!("\"{}", "#".repeat(hashes));
+    code[(body_start + 1)..]
+        .find(&closing)
+        .map(|position| body_start + 1 + position + closing.len())
+}
+
+fn str_end(code: &str) -> usize {
+    let mut escaped = false;
+
+    for (index, character) in code.char_indices().skip(1) {
+        match character {
+            '\\' if !escaped => escaped = true,
+            '"' if !escaped => return index + 1,
+            _ => escaped = false,
+        }
+    }
+
+    code.len()
+}
+
+fn char_literal_end(code: &str) -> Option<usize> {
+    let mut characters = code.char_indices().skip(1);
+
+    match characters.next()? {
+        (_, '\\') => characters
+            .skip(1)
+            .find(|(_, character)| *character == '\'')
+            .map(|(index, _)| index + 1),
+        _ => match characters.next()? {
+            (index, '\'') => Some(index + 1),
+            _ => None,
+        },
+    }
+}
+
+fn literal_end(code: &str) -> Option<usize> {
+    match code.chars().next()? {
+        '"' => Some(str_end(code)),
+        '\'' => char_literal_end(code),
+        'r' => raw_str_end(code),
+        '/' if code.starts_with("//") => Some(code.find('\n').unwrap_or(code.len())),
+        '/' if code.starts_with("/*") => Some(
+            code[2..]
+                .find("*/")
+                .map(|position| position + 4)
+                .unwrap_or(code.len()),
+        ),
+        _ => None,
+    }
+}
+
+fn word_ranges(code: &str) -> Vec<(Range<usize>, i32)> {
+    let mut ranges = Vec::new();
+    let mut depth = 0;
+    let mut index = 0;
+
+    while let Some(character) = code[index..].chars().next() {
+        let is_word_start = index == 0 || !code[..index].ends_with(is_word_character);
+        if is_word_start {
+            if let Some(end) = literal_end(&code[index..]) {
+                index += end;
+                continue;
+            }
+        }
+
+        match character {
+            '{' => depth += 1,
+            '}' => depth -= 1,
+            character if is_word_character(character) => {
+                let length = code[index..]
+                    .find(|character| !is_word_character(character))
+                    .unwrap_or(code.len() - index);
+                ranges.push((index..(index + length), depth));
+                index += length;
+                continue;
+            }
+            _ => {}
+        }
+
+        index += character.len_utf8();
+    }
+
+    ranges
+}
+
+fn pattern_end(code: &str, keyword: &str) -> usize {
+    let end = match keyword {
+        "for" => code.find(" in "),
+        _ => code.find('='),
+    };
+
+    end.unwrap_or(code.len())
+}
+
+pub fn collect_bindings(code: &str, bindings: &mut HashSet<String>) {
+    let words = word_ranges(code);
+    let mut pattern_range: Option<Range<usize>> = None;
+
+    for (word_range, depth) in words {
+        let word = &code[word_range.clone()];
+
+        match &pattern_range {
+            _ if depth > 0 => {}
+            Some(range) if range.contains(&word_range.start) => {
+                let is_binding = word
+                    .starts_with(|character: char| character.is_lowercase() || character == '_')
+                    && !PATTERN_KEYWORDS.contains(&word);
+
+                if is_binding {
+                    bindings.insert(word.to_string());
+                }
+            }
+            _ if matches!(word, "let" | "for" | "set") => {
+                let rest = &code[word_range.end..];
+                pattern_range = Some(word_range.end..(word_range.end + pattern_end(rest, word)));
+            }
+            _ => {}
+        }
+    }
+}
+
+pub fn mentions(code: &str, identifier: &str) -> bool {
+    word_ranges(code)
+        .into_iter()
+        .any(|(word_range, _)| &code[word_range] == identifier)
+}
+
+pub fn is_identifier(expression: &str) -> bool {
+    let expression = expression.trim();
+
+    expression.starts_with(|character: char| character.is_alphabetic() || character == '_')
+        && expression
+            .chars()
+            .all(|character| character.is_alphanumeric() || character == '_')
+        && !VALUE_KEYWORDS.contains(&expression)
+}
+
+#[cfg(test)]
+mod binding_parse_tests {
+    use std::collections::HashSet;
+
+    use super::{collect_bindings, is_identifier, mentions};
+
+    fn bindings(code: &str) -> Vec<String> {
+        let mut bindings = HashSet::new();
+        collect_bindings(code, &mut bindings);
+
+        let mut bindings: Vec<String> = bindings.into_iter().collect();
+        bindings.sort();
+        bindings
+    }
+
+    #[test]
+    fn collect_let_bindings() {
+        assert_eq!(
+            bindings("let mut count = 0; let (a, _b) = (1, 2);"),
+            vec!["_b", "a", "count"]
+        );
+    }
+
+    #[test]
+    fn collect_pattern_bindings() {
+        assert_eq!(bindings("if let Some(ref name) = self.name"), vec!["name"]);
+        assert_eq!(
+            bindings("for (index, item) in items.iter()"),
+            vec!["index", "item"]
+        );
+    }
+
+    #[test]
+    fn skip_nested_bindings() {
+        assert_eq!(bindings("let a = 1; if a > 0 { let b = 2; }"), vec!["a"]);
+        assert_eq!(bindings("} else if let Some(c) = d {"), vec!["c"]);
+    }
+
+    #[test]
+    fn skip_bindings_in_literals() {
+        assert_eq!(bindings(r#"let x = "let y = 1";"#), vec!["x"]);
+        assert_eq!(
+            bindings(r##"let s = r#"{"#; let z = '{';"##),
+            vec!["s", "z"]
+        );
+        assert_eq!(bindings("let a = 1; // let b = 2;"), vec!["a"]);
+    }
+
+    #[test]
+    fn collect_set_binding() {
+        assert_eq!(bindings(" set total = self.a + self.b "), vec!["total"]);
+    }
+
+    #[test]
+    fn detect_mentioned_identifier() {
+        assert!(mentions(" write!(f, \"{}\", x)?; ", "f"));
+        assert!(mentions("for x in &self.xs { f.write_str(x)?; }", "f"));
+        assert!(!mentions("let fx = self.f2;", "f"));
+        assert!(!mentions(r#"let label = "f";"#, "f"));
+        assert!(!mentions("let quote = '\\''; let c = 'f';", "f"));
+        assert!(!mentions("/* f */ let x = 1;", "f"));
+    }
+
+    #[test]
+    fn detect_identifier() {
+        assert!(is_identifier(" title "));
+        assert!(!is_identifier("self"));
+        assert!(!is_identifier("self.title"));
+        assert!(!is_identifier("title()"));
+    }
+}
@@ -0,0 +1,103 @@
+use std::collections::HashMap;
+
+#[derive(PartialEq, Eq, Debug)]
+pub enum CatalogParseError {
+    InvalidLine(usize),
+    MissingLocale,
+}
+
+impl std::fmt::Display for CatalogParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CatalogParseError::InvalidLine(line) => write!(
+                f,
+                "Invalid catalog line {}, expected `[locale]` or `key = message`",
+                line
+            ),
+            CatalogParseError::MissingLocale => {
+                f.write_str("The locale has no section in the catalog")
+            }
+        }
+    }
+}
+
+pub fn parse_catalog(
+    catalog: &str,
+    locale: &str,
+) -> Result<HashMap<String, String>, CatalogParseError> {
+    let mut messages = HashMap::new();
+    let mut current_locale = None;
+    let mut has_locale = false;
+
+    for (index, line) in catalog.lines().enumerate() {
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        if let Some(section) = line
+            .strip_prefix('[')
+            .and_then(|section| section.strip_suffix(']'))
+        {
+            current_locale = Some(section.trim());
+            has_locale |= section.trim() == locale;
+            continue;
+        }
+
+        let Some((key, message)) = line.split_once('=') else {
+            return Err(CatalogParseError::InvalidLine(index + 1));
+        };
+
+        match current_locale {
+            Some(current_locale) if current_locale == locale => {
+                messages.insert(key.trim().to_string(), message.trim().to_string());
+            }
+            Some(_) => {}
+            None => return Err(CatalogParseError::InvalidLine(index + 1)),
+        }
+    }
+
+    match has_locale {
+        true => Ok(messages),
+        false => Err(CatalogParseError::MissingLocale),
+    }
+}
+
+#[cfg(test)]
+mod catalog_parse_tests {
+    use super::{parse_catalog, CatalogParseError};
+
+    const CATALOG: &str =
+        "# greetings\n[en]\ngreeting = Hello\nfarewell = Bye = Ciao\n\n[de]\ngreeting = Hallo\n";
+
+    #[test]
+    fn parse_locale_section() {
+        let messages = parse_catalog(CATALOG, "en").unwrap();
+
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages["greeting"], "Hello");
+        assert_eq!(messages["farewell"], "Bye = Ciao");
+        assert_eq!(parse_catalog(CATALOG, "de").unwrap()["greeting"], "Hallo");
+    }
+
+    #[test]
+    fn parse_missing_locale() {
+        assert_eq!(
+            parse_catalog(CATALOG, "fr"),
+            Err(CatalogParseError::MissingLocale)
+        );
+    }
+
+    #[test]
+    fn parse_invalid_lines() {
+        assert_eq!(
+            parse_catalog("greeting = Hello", "en"),
+            Err(CatalogParseError::InvalidLine(1))
+        );
+        assert_eq!(
+            parse_catalog("[en]\ngreeting", "en"),
+            Err(CatalogParseError::InvalidLine(2))
+        );
+    }
+}
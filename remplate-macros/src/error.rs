@@ -1,9 +1,28 @@
-use std::{ops::Range, path::PathBuf};
+use std::{ops::Range, path::Path};
 
 #[derive(Debug)]
 pub enum TemplateErrorKind {
     ClosingToken,
     MissingValue,
+    UnclosedScope,
+    UnexpectedScopeEnd,
+    UnreadableInclude,
+    UnbalancedDelimiter,
+    UnknownEscape,
+    InvalidCode,
+    IncludeDepth,
+    UnexpectedSeparator,
+    InvalidFormatSpec,
+    InvalidRegionName,
+    DuplicateRegion,
+    ReservedRegionName,
+    OffsetFeature,
+    MissingCatalog,
+    MissingTranslation,
+    DynamicContent,
+    MisplacedExtends,
+    UnknownBlock,
+    DuplicateBlock,
 }
 
 impl std::fmt::Display for TemplateErrorKind {
@@ -11,6 +30,37 @@ impl std::fmt::Display for TemplateErrorKind {
         f.write_str(match self {
             TemplateErrorKind::ClosingToken => "Failed to find closing token for",
             TemplateErrorKind::MissingValue => "The format expression misses a value -",
+            TemplateErrorKind::UnclosedScope => "Failed to find the end of the scope opened by",
+            TemplateErrorKind::UnreadableInclude => "Unable to read the file included by",
+            TemplateErrorKind::UnbalancedDelimiter => "Failed to find the matching delimiter for",
+            TemplateErrorKind::UnknownEscape => "Unknown escape context in",
+            TemplateErrorKind::InvalidCode => "Unable to tokenize the code",
+            TemplateErrorKind::IncludeDepth => "Too deeply nested include",
+            TemplateErrorKind::UnexpectedSeparator => "Failed to find the loop of the separator",
+            TemplateErrorKind::InvalidFormatSpec => "Invalid format spec",
+            TemplateErrorKind::InvalidRegionName => "The region name is not an identifier in",
+            TemplateErrorKind::DuplicateRegion => {
+                "A region with the same name is already defined by"
+            }
+            TemplateErrorKind::ReservedRegionName => {
+                "The region name collides with a render method of the template in"
+            }
+            TemplateErrorKind::OffsetFeature => "The `offset` feature is required by",
+            TemplateErrorKind::MissingCatalog => {
+                "The `locale` and `catalog` options are required by"
+            }
+            TemplateErrorKind::MissingTranslation => "The catalog has no message for",
+            TemplateErrorKind::DynamicContent => {
+                "Templates with the `static_only` option can not contain the code block"
+            }
+            TemplateErrorKind::MisplacedExtends => {
+                "The `extends` directive has to start the template -"
+            }
+            TemplateErrorKind::UnknownBlock => "The extended template defines no block named by",
+            TemplateErrorKind::DuplicateBlock => "A block with the same name is already defined by",
+            TemplateErrorKind::UnexpectedScopeEnd => {
+                "Failed to find the start of the scope closed by"
+            }
         })
     }
 }
@@ -18,16 +68,54 @@ impl std::fmt::Display for TemplateErrorKind {
 #[derive(Debug)]
 pub struct TemplateError<'a>(
     pub Range<usize>,
-    pub &'a PathBuf,
+    pub &'a Path,
     pub &'a str,
     pub TemplateErrorKind,
     pub proc_macro2::Span,
 );
 
+impl<'a>
+    From<(
+        remplate_parser::TemplateParseError,
+        &'a Path,
+        &'a str,
+        proc_macro2::Span,
+    )> for TemplateError<'a>
+{
+    fn from(
+        (error, template_path, template, error_span): (
+            remplate_parser::TemplateParseError,
+            &'a Path,
+            &'a str,
+            proc_macro2::Span,
+        ),
+    ) -> Self {
+        let position = error.position();
+
+        TemplateError(
+            position..(position + 1),
+            template_path,
+            template,
+            TemplateErrorKind::ClosingToken,
+            error_span,
+        )
+    }
+}
+
+impl From<TemplateError<'_>> for syn::Error {
+    fn from(error: TemplateError<'_>) -> Self {
+        error.into_syn_error()
+    }
+}
+
 impl<'a> TemplateError<'a> {
     const TEMPLATE_POINTER_PADDING: usize = 20;
 
     pub fn abortion_error(self) -> proc_macro2::TokenStream {
+        syn::Error::from(self).into_compile_error()
+    }
+
+    fn into_syn_error(self) -> syn::Error {
         let left_slice = self.left_erroneous_slice();
         let right_slice = self.right_erroneous_slice();
         let erroneous_character = self.erroneous_character();
@@ -57,14 +145,17 @@ impl<'a> TemplateError<'a> {
             pointer
         );
 
-        syn::Error::new(span, error_message).into_compile_error()
+        syn::Error::new(span, error_message)
     }
 
     fn left_erroneous_slice(&self) -> String {
         let TemplateError(erroneous_character_position, _, template, _, _) = self;
-        let slice_start = erroneous_character_position
+        let mut slice_start = erroneous_character_position
             .start
             .saturating_sub(Self::TEMPLATE_POINTER_PADDING);
+        while !template.is_char_boundary(slice_start) {
+            slice_start -= 1;
+        }
         format!(
             "{:?}",
             &template[slice_start..erroneous_character_position.start]
@@ -73,10 +164,13 @@ impl<'a> TemplateError<'a> {
 
     fn right_erroneous_slice(&self) -> String {
         let TemplateError(erroneous_character_position, _, template, _, _) = self;
-        let slice_end = std::cmp::min(
+        let mut slice_end = std::cmp::min(
             erroneous_character_position.end + Self::TEMPLATE_POINTER_PADDING,
             template.len(),
         );
+        while !template.is_char_boundary(slice_end) {
+            slice_end += 1;
+        }
         format!(
             "{:?}",
             &template[(erroneous_character_position.end)..slice_end]
@@ -0,0 +1,527 @@
+use std::ops::Range;
+
+pub const FILTER_NAMES: [&str; 20] = [
+    "attr",
+    "bin",
+    "css",
+    "csv",
+    "date",
+    "group",
+    "hex",
+    "html",
+    "join",
+    "js",
+    "json",
+    "jsonpath",
+    "ok_or_err",
+    "oct",
+    "path",
+    "raw",
+    "sql",
+    "truncate",
+    "unquoted_attr",
+    "xml",
+];
+
+pub const ESCAPE_NAMES: [&str; 8] = [
+    "css",
+    "csv",
+    "html",
+    "js",
+    "json",
+    "sql",
+    "unquoted_attr",
+    "xml",
+];
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Filter {
+    pub name_range: Range<usize>,
+    pub arguments_range: Option<Range<usize>>,
+}
+
+#[derive(PartialEq, Eq, Debug)]
+pub struct FilterParseResult {
+    pub expression_end: usize,
+    pub filters: Vec<Filter>,
+}
+
+impl Filter {
+    pub fn offset(self, offset: usize) -> Self {
+        Filter {
+            name_range: (self.name_range.start + offset)..(self.name_range.end + offset),
+            arguments_range: self.arguments_range.map(|arguments_range| {
+                (arguments_range.start + offset)..(arguments_range.end + offset)
+            }),
+        }
+    }
+}
+
+pub fn parse_filters(input: &str) -> FilterParseResult {
+    let mut expression_end = input.len();
+    let mut filters = Vec::new();
+
+    for separator_position in filter_separator_positions(input).into_iter().rev() {
+        match parse_filter(&input[(separator_position + 1)..expression_end]) {
+            Some(filter) => {
+                filters.push(filter.offset(separator_position + 1));
+                expression_end = separator_position;
+            }
+            None => break,
+        }
+    }
+
+    filters.reverse();
+
+    FilterParseResult {
+        expression_end,
+        filters,
+    }
+}
+
+pub fn helper_call_arguments(expression: &str, helper_name: &str) -> Option<Range<usize>> {
+    let arguments_start = expression.len() - expression.trim_start().len() + helper_name.len() + 1;
+    let arguments = expression
+        .trim()
+        .strip_prefix(helper_name)?
+        .strip_prefix('(')?
+        .strip_suffix(')')?;
+
+    let mut open_delimiters = 0usize;
+    let mut in_str = false;
+    let mut escaped = false;
+    let mut characters = arguments.chars();
+
+    while let Some(character) = characters.next() {
+        if in_str {
+            match character {
+                '\\' if !escaped => escaped = true,
+                '"' if !escaped => in_str = false,
+                _ => escaped = false,
+            }
+            continue;
+        }
+
+        match character {
+            '"' => in_str = true,
+            '\'' => {
+                let mut literal = characters.clone();
+                let is_char_literal = match literal.next() {
+                    Some('\\') => literal.by_ref().skip(1).any(|character| character == '\''),
+                    Some(_) => literal.next() == Some('\''),
+                    None => false,
+                };
+
+                if is_char_literal {
+                    characters = literal;
+                }
+            }
+            '(' => open_delimiters += 1,
+            ')' if open_delimiters == 0 => return None,
+            ')' => open_delimiters -= 1,
+            _ => {}
+        }
+    }
+
+    Some(arguments_start..(arguments_start + arguments.len()))
+}
+
+pub fn format_spec_position(input: &str) -> Option<usize> {
+    let mut open_delimiters = 0usize;
+    let mut in_str = false;
+    let mut escaped = false;
+    let bytes = input.as_bytes();
+
+    for (index, character) in input.char_indices() {
+        if in_str {
+            match character {
+                '\\' if !escaped => escaped = true,
+                '"' if !escaped => in_str = false,
+                _ => escaped = false,
+            }
+            continue;
+        }
+
+        match character {
+            '"' => in_str = true,
+            '(' | '[' | '{' => open_delimiters += 1,
+            ')' | ']' | '}' => open_delimiters = open_delimiters.saturating_sub(1),
+            ':' if open_delimiters == 0 => {
+                let is_path_separator =
+                    (index > 0 && bytes[index - 1] == b':') || bytes.get(index + 1) == Some(&b':');
+
+                if !is_path_separator {
+                    return Some(index);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+fn top_level_char_indices(input: &str) -> Vec<(usize, char)> {
+    let mut char_indices = Vec::new();
+    let mut open_delimiters = 0usize;
+    let mut in_str = false;
+    let mut escaped = false;
+
+    for (index, character) in input.char_indices() {
+        if in_str {
+            match character {
+                '\\' if !escaped => escaped = true,
+                '"' if !escaped => in_str = false,
+                _ => escaped = false,
+            }
+            continue;
+        }
+
+        match character {
+            '"' => in_str = true,
+            '(' | '[' | '{' => open_delimiters += 1,
+            ')' | ']' | '}' => open_delimiters = open_delimiters.saturating_sub(1),
+            character if open_delimiters == 0 => char_indices.push((index, character)),
+            _ => {}
+        }
+    }
+
+    char_indices
+}
+
+pub fn statement_end_position(input: &str) -> Option<usize> {
+    top_level_char_indices(input)
+        .into_iter()
+        .rev()
+        .find(|(_, character)| *character == ';')
+        .map(|(index, _)| index)
+}
+
+fn starts_with_keyword(input: &str, keyword: &str) -> bool {
+    input.strip_prefix(keyword).is_some_and(|rest| {
+        rest.is_empty() || rest.starts_with(|character: char| !is_identifier_char(character))
+    })
+}
+
+fn is_identifier_char(character: char) -> bool {
+    character.is_alphanumeric() || character == '_'
+}
+
+pub fn is_statement(input: &str) -> bool {
+    let input = input.trim();
+
+    if ["for", "while", "loop"]
+        .iter()
+        .any(|keyword| starts_with_keyword(input, keyword))
+    {
+        return true;
+    }
+
+    starts_with_keyword(input, "if")
+        && !top_level_char_indices(input).into_iter().any(|(index, _)| {
+            let is_word_start = index == 0 || !input[..index].ends_with(is_identifier_char);
+            is_word_start && starts_with_keyword(&input[index..], "else")
+        })
+}
+
+pub fn list_element_ranges(input: &str) -> Option<Vec<Range<usize>>> {
+    let list_start = input.len() - input.trim_start().len();
+    let list = input.trim();
+    let list_end = list_start + list.len();
+
+    if !list.starts_with('[') || !list.ends_with(']') {
+        return None;
+    }
+
+    let mut element_ranges = Vec::new();
+    let mut element_start = list_start + 1;
+    let mut open_delimiters = 0usize;
+    let mut in_str = false;
+    let mut escaped = false;
+
+    for (index, character) in list.char_indices() {
+        let index = list_start + index;
+
+        if in_str {
+            match character {
+                '\\' if !escaped => escaped = true,
+                '"' if !escaped => in_str = false,
+                _ => escaped = false,
+            }
+            continue;
+        }
+
+        match character {
+            '"' => in_str = true,
+            '(' | '[' | '{' => open_delimiters += 1,
+            ')' | ']' | '}' => {
+                open_delimiters = open_delimiters.saturating_sub(1);
+
+                if open_delimiters == 0 && index + 1 != list_end {
+                    return None;
+                }
+            }
+            ',' if open_delimiters == 1 => {
+                element_ranges.push(element_start..index);
+                element_start = index + 1;
+            }
+            ';' if open_delimiters == 1 => return None,
+            _ => {}
+        }
+    }
+
+    element_ranges.push(element_start..(list_end - 1));
+    element_ranges.retain(|element_range| !input[element_range.clone()].trim().is_empty());
+
+    Some(element_ranges)
+}
+
+fn filter_separator_positions(input: &str) -> Vec<usize> {
+    let mut positions = Vec::new();
+    let mut open_delimiters = 0usize;
+    let mut in_str = false;
+    let mut escaped = false;
+    let bytes = input.as_bytes();
+
+    for (index, character) in input.char_indices() {
+        if in_str {
+            match character {
+                '\\' if !escaped => escaped = true,
+                '"' if !escaped => in_str = false,
+                _ => escaped = false,
+            }
+            continue;
+        }
+
+        match character {
+            '"' => in_str = true,
+            '(' | '[' | '{' => open_delimiters += 1,
+            ')' | ']' | '}' => open_delimiters = open_delimiters.saturating_sub(1),
+            '|' if open_delimiters == 0 => {
+                let is_double_pipe =
+                    (index > 0 && bytes[index - 1] == b'|') || bytes.get(index + 1) == Some(&b'|');
+
+                if !is_double_pipe {
+                    positions.push(index);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    positions
+}
+
+fn parse_filter(input: &str) -> Option<Filter> {
+    let name_start = input.len() - input.trim_start().len();
+    let trimmed = input.trim();
+    let name_length = trimmed
+        .find(|character: char| !(character.is_alphanumeric() || character == '_'))
+        .unwrap_or(trimmed.len());
+    let name = &trimmed[..name_length];
+
+    if !FILTER_NAMES.contains(&name) {
+        return None;
+    }
+
+    let rest = &trimmed[name_length..];
+    let arguments_range = match rest.trim_start() {
+        "" => None,
+        arguments if arguments.starts_with('(') && arguments.ends_with(')') => {
+            let arguments_start = name_start + name_length + (rest.len() - arguments.len()) + 1;
+            Some(arguments_start..(arguments_start + arguments.len() - 2))
+        }
+        _ => return None,
+    };
+
+    Some(Filter {
+        name_range: name_start..(name_start + name_length),
+        arguments_range,
+    })
+}
+
+#[cfg(test)]
+mod filter_parse_tests {
+    use super::{
+        format_spec_position, helper_call_arguments, is_statement, list_element_ranges,
+        parse_filters, statement_end_position, Filter, FilterParseResult,
+    };
+
+    #[test]
+    fn parse_expression_without_filter() {
+        let to_parse = " self.url ";
+        let result = parse_filters(to_parse);
+        assert_eq!(
+            result,
+            FilterParseResult {
+                expression_end: 10,
+                filters: vec![],
+            }
+        )
+    }
+
+    #[test]
+    fn parse_expression_with_filter() {
+        let to_parse = " self.url | attr ";
+        let result = parse_filters(to_parse);
+        assert_eq!(
+            result,
+            FilterParseResult {
+                expression_end: 10,
+                filters: vec![Filter {
+                    name_range: 12..16,
+                    arguments_range: None,
+                }],
+            }
+        )
+    }
+
+    #[test]
+    fn parse_expression_with_filter_arguments() {
+        let to_parse = r#" self.tags | join(", ") "#;
+        let result = parse_filters(to_parse);
+        assert_eq!(
+            result,
+            FilterParseResult {
+                expression_end: 11,
+                filters: vec![Filter {
+                    name_range: 13..17,
+                    arguments_range: Some(18..22),
+                }],
+            }
+        )
+    }
+
+    #[test]
+    fn parse_expression_with_bit_or() {
+        let to_parse = " self.a | self.b ";
+        let result = parse_filters(to_parse);
+        assert_eq!(
+            result,
+            FilterParseResult {
+                expression_end: 17,
+                filters: vec![],
+            }
+        )
+    }
+
+    #[test]
+    fn parse_expression_with_closure_and_filter() {
+        let to_parse = r#" self.f.map(|x| x.to_string()).unwrap_or("|".into()) | attr"#;
+        let result = parse_filters(to_parse);
+        assert_eq!(
+            result,
+            FilterParseResult {
+                expression_end: 53,
+                filters: vec![Filter {
+                    name_range: 55..59,
+                    arguments_range: None,
+                }],
+            }
+        )
+    }
+
+    #[test]
+    fn parse_helper_call_arguments() {
+        let to_parse = r#" attr("disabled", self.disabled) "#;
+        let result = helper_call_arguments(to_parse, "attr");
+        assert_eq!(result, Some(6..31))
+    }
+
+    #[test]
+    fn parse_helper_call_arguments_with_literal_delimiters() {
+        let to_parse = r#" attr("a)", self.a) "#;
+        let result = helper_call_arguments(to_parse, "attr");
+        assert_eq!(result, Some(6..18));
+
+        let to_parse = r#" attr("b\")(", self.b.contains(')')) "#;
+        let result = helper_call_arguments(to_parse, "attr");
+        assert_eq!(result, Some(6..35));
+    }
+
+    #[test]
+    fn parse_helper_calls_in_expression() {
+        let to_parse = r#" attr("a", true).to_string() + &attr("b", false).to_string() "#;
+        let result = helper_call_arguments(to_parse, "attr");
+        assert_eq!(result, None)
+    }
+
+    #[test]
+    fn parse_expression_with_logical_or() {
+        let to_parse = " self.a || attr ";
+        let result = parse_filters(to_parse);
+        assert_eq!(
+            result,
+            FilterParseResult {
+                expression_end: 16,
+                filters: vec![],
+            }
+        )
+    }
+
+    #[test]
+    fn find_format_spec() {
+        assert_eq!(format_spec_position(" self.count:>4 "), Some(11));
+        assert_eq!(format_spec_position(" :? "), Some(1));
+    }
+
+    #[test]
+    fn find_format_spec_after_call_with_path() {
+        let to_parse = r#" (self.greet)(String::from("a:b")):? "#;
+        assert_eq!(format_spec_position(to_parse), Some(34));
+    }
+
+    #[test]
+    fn find_top_level_statement_end() {
+        assert_eq!(statement_end_position("let a = 1; a"), Some(9));
+        assert_eq!(statement_end_position("if c { let a = 1; }"), None);
+        assert_eq!(
+            statement_end_position(r#"let s = ";"; if c { x; }"#),
+            Some(11)
+        );
+    }
+
+    #[test]
+    fn detect_statements() {
+        assert!(is_statement(" for item in &self.items { x; } "));
+        assert!(is_statement("if c { x; }"));
+        assert!(is_statement("while let Some(x) = it.next() { }"));
+        assert!(!is_statement(r#"if c { "a" } else { "b" }"#));
+        assert!(!is_statement("if_value"));
+        assert!(!is_statement("formatted"));
+        assert!(!is_statement(r#"match n { 1 => "one", _ => "many" }"#));
+    }
+
+    #[test]
+    fn find_format_spec_after_index() {
+        assert_eq!(format_spec_position(r#" self.scores["alice"] "#), None);
+        assert_eq!(format_spec_position(r#" self.scores["a:b"] "#), None);
+        assert_eq!(format_spec_position(r#" self.scores["a"] : >8 "#), Some(18));
+        assert_eq!(format_spec_position(" self.items[1..][0]:? "), Some(19));
+    }
+
+    #[test]
+    fn split_list_elements() {
+        assert_eq!(
+            list_element_ranges(r#" [ self.a, f(1, 2), "[,]", ] "#),
+            Some(vec![2..9, 10..18, 19..25])
+        );
+        assert_eq!(
+            list_element_ranges("[self.a]").map(|ranges| ranges.len()),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn reject_non_list_expressions() {
+        assert_eq!(list_element_ranges("[a, b][0]"), None);
+        assert_eq!(list_element_ranges("[a, b].len()"), None);
+        assert_eq!(list_element_ranges("[0; 3]"), None);
+        assert_eq!(list_element_ranges("self.a"), None);
+    }
+
+    #[test]
+    fn find_no_format_spec_in_path() {
+        assert_eq!(format_spec_position(" std::f64::consts::PI "), None);
+    }
+}
@@ -0,0 +1,163 @@
+use std::ops::Range;
+
+const FORMAT_TRAITS: [&str; 11] = ["", "?", "x?", "X?", "x", "X", "o", "b", "e", "E", "p"];
+
+fn is_align(character: char) -> bool {
+    matches!(character, '<' | '^' | '>')
+}
+
+fn count_end(spec: &str, start: usize) -> usize {
+    let rest = &spec[start..];
+
+    let digits_length = rest
+        .find(|character: char| !character.is_ascii_digit())
+        .unwrap_or(rest.len());
+    if digits_length > 0 {
+        return match rest[digits_length..].starts_with('$') {
+            true => start + digits_length + 1,
+            false => start + digits_length,
+        };
+    }
+
+    let identifier_length = rest
+        .find(|character: char| !(character.is_alphanumeric() || character == '_'))
+        .unwrap_or(rest.len());
+    match identifier_length > 0 && rest[identifier_length..].starts_with('$') {
+        true => start + identifier_length + 1,
+        false => start,
+    }
+}
+
+pub fn spec_argument_ranges(spec: &str) -> Vec<Range<usize>> {
+    let mut argument_ranges = Vec::new();
+    let mut argument_start = None;
+    let mut open_braces = 0usize;
+
+    for (index, character) in spec.char_indices() {
+        match character {
+            '{' => {
+                if open_braces == 0 {
+                    argument_start = Some(index);
+                }
+                open_braces += 1;
+            }
+            '}' if open_braces > 0 => {
+                open_braces -= 1;
+
+                match argument_start {
+                    Some(start) if open_braces == 0 && spec[(index + 1)..].starts_with('$') => {
+                        argument_ranges.push(start..(index + 1))
+                    }
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+    }
+
+    argument_ranges
+}
+
+pub fn invalid_format_spec_position(spec: &str) -> Option<usize> {
+    match format_trait_position(spec) {
+        Ok(position) if FORMAT_TRAITS.contains(&&spec[position..]) => None,
+        Ok(position) | Err(position) => Some(position),
+    }
+}
+
+pub fn format_trait(spec: &str) -> Option<&'static str> {
+    let position = format_trait_position(spec).ok()?;
+
+    match &spec[position..] {
+        "" => Some("display"),
+        "?" | "x?" | "X?" => Some("debug"),
+        "x" => Some("lower_hex"),
+        "X" => Some("upper_hex"),
+        "o" => Some("octal"),
+        "b" => Some("binary"),
+        "e" => Some("lower_exp"),
+        "E" => Some("upper_exp"),
+        _ => None,
+    }
+}
+
+fn format_trait_position(spec: &str) -> Result<usize, usize> {
+    let mut chars = spec.chars();
+    let mut position = match (chars.next(), chars.next()) {
+        (Some(fill), Some(align)) if is_align(align) => fill.len_utf8() + 1,
+        (Some(align), _) if is_align(align) => 1,
+        _ => 0,
+    };
+
+    if spec[position..].starts_with(['+', '-']) {
+        position += 1;
+    }
+    for flag in ['#', '0'] {
+        if spec[position..].starts_with(flag) {
+            position += 1;
+        }
+    }
+
+    position = count_end(spec, position);
+
+    if spec[position..].starts_with('.') {
+        position += 1;
+
+        let precision_end = match spec[position..].starts_with('*') {
+            true => position + 1,
+            false => count_end(spec, position),
+        };
+        if precision_end == position {
+            return Err(position);
+        }
+        position = precision_end;
+    }
+
+    Ok(position)
+}
+
+#[cfg(test)]
+mod format_spec_parse_tests {
+    use super::{format_trait, invalid_format_spec_position, spec_argument_ranges};
+
+    #[test]
+    fn valid_format_specs() {
+        for spec in [
+            "", "?", "#?", ">4", ">>8", "*^10", "+.2", "#010x", "08.3e", "width$", "1$.prec$",
+            ".*", "x?", "-", "<5b",
+        ] {
+            assert_eq!(invalid_format_spec_position(spec), None, "{}", spec);
+        }
+    }
+
+    #[test]
+    fn invalid_format_specs() {
+        assert_eq!(invalid_format_spec_position("<<<8"), Some(2));
+        assert_eq!(invalid_format_spec_position(">8z"), Some(2));
+        assert_eq!(invalid_format_spec_position(".x"), Some(1));
+        assert_eq!(invalid_format_spec_position("+-4"), Some(1));
+    }
+
+    #[test]
+    fn format_traits_of_specs() {
+        assert_eq!(format_trait(""), Some("display"));
+        assert_eq!(format_trait(">8"), Some("display"));
+        assert_eq!(format_trait("x>"), Some("display"));
+        assert_eq!(format_trait("#?"), Some("debug"));
+        assert_eq!(format_trait("x?"), Some("debug"));
+        assert_eq!(format_trait("#010x"), Some("lower_hex"));
+        assert_eq!(format_trait("08.3e"), Some("lower_exp"));
+        assert_eq!(format_trait("p"), None);
+        assert_eq!(format_trait(".x"), None);
+    }
+
+    #[test]
+    fn find_spec_arguments() {
+        assert_eq!(spec_argument_ranges(">{self.width}$"), vec![1..13]);
+        assert_eq!(
+            spec_argument_ranges("{widths[0]}$.{ f(1) }$"),
+            vec![0..11, 13..21]
+        );
+        assert_eq!(spec_argument_ranges(">{width}"), vec![]);
+    }
+}
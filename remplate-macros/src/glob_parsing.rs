@@ -0,0 +1,95 @@
+use std::path::{Path, PathBuf};
+
+pub fn split_glob(glob: &str) -> Option<(&str, &str)> {
+    let (directory, file_pattern) = match glob.rfind('/') {
+        Some(position) => (&glob[..position], &glob[(position + 1)..]),
+        None => ("", glob),
+    };
+
+    match directory.contains('*') || file_pattern.is_empty() {
+        true => None,
+        false => Some((directory, file_pattern)),
+    }
+}
+
+pub fn matches(pattern: &str, name: &str) -> bool {
+    match pattern.split_once('*') {
+        Some((prefix, rest)) => match name.strip_prefix(prefix) {
+            Some(name) => (0..=name.len())
+                .filter(|position| name.is_char_boundary(*position))
+                .any(|position| matches(rest, &name[position..])),
+            None => false,
+        },
+        None => pattern == name,
+    }
+}
+
+pub fn expand_glob(directory: &Path, file_pattern: &str) -> std::io::Result<Vec<PathBuf>> {
+    let mut paths = Vec::new();
+
+    for entry in std::fs::read_dir(directory)? {
+        let entry = entry?;
+
+        let is_match = entry
+            .file_name()
+            .to_str()
+            .is_some_and(|name| matches(file_pattern, name));
+        if is_match && entry.file_type()?.is_file() {
+            paths.push(entry.path());
+        }
+    }
+
+    paths.sort();
+
+    Ok(paths)
+}
+
+pub fn method_name(directory: &str) -> String {
+    let directory_name = match directory.rsplit('/').next() {
+        Some("") | None => "variant",
+        Some(directory_name) => directory_name,
+    };
+
+    format!("render_{}", directory_name.replace('-', "_"))
+}
+
+#[cfg(test)]
+mod glob_parse_tests {
+    use super::{matches, method_name, split_glob};
+
+    #[test]
+    fn split_globs() {
+        assert_eq!(split_glob("themes/*.html"), Some(("themes", "*.html")));
+        assert_eq!(
+            split_glob("tests/templates/themes/*.html"),
+            Some(("tests/templates/themes", "*.html"))
+        );
+        assert_eq!(split_glob("*.html"), Some(("", "*.html")));
+        assert_eq!(split_glob("*/page.html"), None);
+        assert_eq!(split_glob("themes/"), None);
+    }
+
+    #[test]
+    fn match_file_names() {
+        assert!(matches("*.html", "dark.html"));
+        assert!(matches("*", "dark.html"));
+        assert!(matches("theme_*.html", "theme_dark.html"));
+        assert!(matches("*_*.html", "a_b.html"));
+        assert!(matches("dark.html", "dark.html"));
+        assert!(!matches("*.html", "dark.txt"));
+        assert!(!matches("theme_*.html", "dark.html"));
+        assert!(!matches("*.html", "dark.html.bak"));
+    }
+
+    #[test]
+    fn method_names() {
+        assert_eq!(method_name("themes"), "render_themes");
+        assert_eq!(method_name("class"), "render_class");
+        assert_eq!(
+            method_name("tests/templates/color-schemes"),
+            "render_color_schemes"
+        );
+        assert_eq!(method_name("layout"), "render_layout");
+        assert_eq!(method_name(""), "render_variant");
+    }
+}
@@ -0,0 +1,161 @@
+const RAW_TEXT_ELEMENTS: [&str; 2] = ["script", "style"];
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum HtmlContext {
+    #[default]
+    Text,
+    Comment,
+    Tag(Option<&'static str>),
+    AttributeValue(char, Option<&'static str>),
+    RawText(&'static str),
+}
+
+fn tag_name(fragment: &str) -> &str {
+    let name_length = fragment
+        .find(|character: char| !(character.is_ascii_alphanumeric() || character == '-'))
+        .unwrap_or(fragment.len());
+
+    &fragment[..name_length]
+}
+
+fn starts_with_ignore_case(fragment: &str, prefix: &str) -> bool {
+    fragment
+        .get(..prefix.len())
+        .is_some_and(|start| start.eq_ignore_ascii_case(prefix))
+}
+
+impl HtmlContext {
+    pub fn advance(self, fragment: &str) -> Self {
+        let mut context = self;
+        let mut position = 0;
+
+        while let Some(character) = fragment[position..].chars().next() {
+            let rest = &fragment[position..];
+            let mut next_position = position + character.len_utf8();
+
+            context = match context {
+                HtmlContext::Text if rest.starts_with("<!--") => {
+                    next_position = position + 4;
+                    HtmlContext::Comment
+                }
+                HtmlContext::Text
+                    if character == '<'
+                        && rest[1..].starts_with(|character: char| {
+                            character.is_ascii_alphabetic() || matches!(character, '/' | '!')
+                        }) =>
+                {
+                    let name = tag_name(&rest[1..]);
+                    next_position += name.len();
+
+                    HtmlContext::Tag(
+                        RAW_TEXT_ELEMENTS
+                            .into_iter()
+                            .find(|element| element.eq_ignore_ascii_case(name)),
+                    )
+                }
+                HtmlContext::Comment if rest.starts_with("-->") => {
+                    next_position = position + 3;
+                    HtmlContext::Text
+                }
+                HtmlContext::Tag(Some(element)) if character == '>' => {
+                    HtmlContext::RawText(element)
+                }
+                HtmlContext::Tag(None) if character == '>' => HtmlContext::Text,
+                HtmlContext::Tag(element) if matches!(character, '"' | '\'') => {
+                    HtmlContext::AttributeValue(character, element)
+                }
+                HtmlContext::AttributeValue(quote, element) if character == quote => {
+                    HtmlContext::Tag(element)
+                }
+                HtmlContext::RawText(element)
+                    if starts_with_ignore_case(rest, &format!("</{}", element)) =>
+                {
+                    next_position = position + 2 + element.len();
+                    HtmlContext::Tag(None)
+                }
+                context => context,
+            };
+
+            position = next_position;
+        }
+
+        context
+    }
+
+    pub fn escape(self) -> &'static str {
+        match self {
+            HtmlContext::Text | HtmlContext::Comment => "html",
+            HtmlContext::Tag(_) => "unquoted_attr",
+            HtmlContext::AttributeValue(..) => "attr",
+            HtmlContext::RawText("style") => "css",
+            HtmlContext::RawText(_) => "js",
+        }
+    }
+}
+
+#[cfg(test)]
+mod html_context_tests {
+    use super::HtmlContext;
+
+    fn context_after(fragments: &[&str]) -> HtmlContext {
+        fragments
+            .iter()
+            .fold(HtmlContext::default(), |context, fragment| {
+                context.advance(fragment)
+            })
+    }
+
+    #[test]
+    fn infer_text_context() {
+        assert_eq!(context_after(&["<p>"]), HtmlContext::Text);
+        assert_eq!(context_after(&["<p>", "</p><b>"]), HtmlContext::Text);
+        assert_eq!(context_after(&["<!-- <script> -->"]), HtmlContext::Text);
+        assert_eq!(context_after(&["<p>1 < 2 and "]), HtmlContext::Text);
+    }
+
+    #[test]
+    fn infer_attribute_context() {
+        assert_eq!(
+            context_after(&["<a href=\""]),
+            HtmlContext::AttributeValue('"', None)
+        );
+        assert_eq!(
+            context_after(&["<a title='", "' href=\""]),
+            HtmlContext::AttributeValue('"', None)
+        );
+        assert_eq!(
+            context_after(&["<a href=\"", "\" "]),
+            HtmlContext::Tag(None)
+        );
+        assert_eq!(context_after(&["<a title=\"a>b\">"]), HtmlContext::Text);
+    }
+
+    #[test]
+    fn infer_raw_text_context() {
+        assert_eq!(
+            context_after(&["<script type=\"module\">const a = "]),
+            HtmlContext::RawText("script")
+        );
+        assert_eq!(
+            context_after(&["<SCRIPT>", "; const b = \"<p>\"; "]),
+            HtmlContext::RawText("script")
+        );
+        assert_eq!(
+            context_after(&["<style>p { color: "]),
+            HtmlContext::RawText("style")
+        );
+        assert_eq!(
+            context_after(&["<script>", "</script><p>"]),
+            HtmlContext::Text
+        );
+    }
+
+    #[test]
+    fn escape_for_context() {
+        assert_eq!(HtmlContext::Text.escape(), "html");
+        assert_eq!(HtmlContext::AttributeValue('"', None).escape(), "attr");
+        assert_eq!(HtmlContext::Tag(None).escape(), "unquoted_attr");
+        assert_eq!(HtmlContext::RawText("script").escape(), "js");
+        assert_eq!(HtmlContext::RawText("style").escape(), "css");
+    }
+}
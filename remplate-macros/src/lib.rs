@@ -1,51 +1,395 @@
 use std::{
+    collections::{hash_map::DefaultHasher, HashMap, HashSet},
+    hash::{Hash, Hasher},
     iter,
     ops::Range,
     path::{Path, PathBuf},
     str::FromStr,
-    sync::OnceLock,
+    sync::{Arc, Mutex, OnceLock},
 };
 
 use error::TemplateError;
-use macro_parsing::{MacroParseResult, RemplatePath};
+use macro_parsing::{
+    MacroParseResult, RemplateAttributes, RemplateCatalog, RemplateContext, RemplateEach,
+    RemplateEstimatedSizeExpr, RemplateLocale, RemplateMethod, RemplatePath, RemplatePathFrom,
+    RemplatePostprocess, RemplatePrecompress, RemplateRoot, RemplateTemplatePath, RemplateUse,
+    RenderWithInput,
+};
 
+mod binding_parsing;
+mod catalog_parsing;
 mod error;
+mod filter_parsing;
+mod format_spec_parsing;
+mod glob_parsing;
+mod html_context;
 mod macro_parsing;
+mod method_generation;
+mod minify;
 mod span_manipulation;
-mod template_parsing;
 
 enum TemplateExpression<'a> {
+    Comment,
+    Empty,
+    IncludeStatic(Range<usize>, String),
+    Include(Range<usize>, String, proc_macro2::TokenStream),
+    EscapeStart(Range<usize>, &'a str),
+    EscapeEnd(Range<usize>),
+    RegionStart(Range<usize>, &'a str),
+    RegionEnd(Range<usize>),
+    Extends(Range<usize>, String),
+    BlockStart(Range<usize>, &'a str),
+    BlockEnd(Range<usize>),
+    Offset(Range<usize>),
+    Translation(Range<usize>, String),
+    Set(&'a str, Range<usize>),
+    LoopStart(&'a str, Range<usize>, Range<usize>),
+    LoopSeparator(&'a str, Range<usize>),
+    LoopEnd(&'a str, Range<usize>),
     CodeBlock(&'a str, Range<usize>),
     CodeBlockWithFormattable((&'a str, Range<usize>), Formattable<'a>),
     Formattable(Formattable<'a>),
+    FormattableList(Vec<Formattable<'a>>),
+    ScopeStart(&'a str, Range<usize>),
+    ScopeEnd(&'a str, Range<usize>),
+    ScopeContinuation(&'a str, Range<usize>, Range<usize>),
 }
 
 impl<'a> TemplateExpression<'a> {
-    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream, error_span: proc_macro2::Span) {
+    fn to_tokens(
+        &self,
+        scopes: &mut ScopeStack,
+        template_path: &'a Path,
+        error_span: proc_macro2::Span,
+    ) -> Result<(), TemplateError<'a>> {
         match self {
-            TemplateExpression::CodeBlock(template, code_block_range) => {
-                match proc_macro2::TokenStream::from_str(&template[code_block_range.clone()]) {
-                    Ok(code) => tokens.extend(code),
-                    Err(error) => tokens.extend(
-                        syn::Error::new(error.span(), error.to_string()).to_compile_error(),
-                    ),
+            TemplateExpression::Comment
+            | TemplateExpression::Empty
+            | TemplateExpression::IncludeStatic(..)
+            | TemplateExpression::Include(..)
+            | TemplateExpression::EscapeStart(..)
+            | TemplateExpression::EscapeEnd(..)
+            | TemplateExpression::RegionStart(..)
+            | TemplateExpression::RegionEnd(..)
+            | TemplateExpression::Extends(..)
+            | TemplateExpression::BlockStart(..)
+            | TemplateExpression::BlockEnd(..)
+            | TemplateExpression::Translation(..) => {}
+            TemplateExpression::Offset(_) => scopes.current().extend(quote::quote! {
+                {
+                    let __remplate_offset = f.position();
+                    ::core::fmt::Write::write_fmt(f, format_args!("{}", __remplate_offset))?;
                 }
+            }),
+            TemplateExpression::CodeBlock(template, code_block_range) => {
+                let code = template_code_tokens(
+                    template,
+                    code_block_range.clone(),
+                    template_path,
+                    error_span,
+                )?;
+
+                scopes.current().extend(code);
+            }
+            TemplateExpression::Set(template, binding_range) => {
+                let binding = template_code_tokens(
+                    template,
+                    binding_range.clone(),
+                    template_path,
+                    error_span,
+                )?;
+
+                scopes.current().extend(quote::quote! {
+                    let #binding;
+                });
             }
             TemplateExpression::CodeBlockWithFormattable(
                 (template, code_block_range),
                 formattable,
             ) => {
-                match proc_macro2::TokenStream::from_str(&template[code_block_range.clone()]) {
-                    Ok(code) => tokens.extend(code),
-                    Err(error) => tokens.extend(
-                        syn::Error::new(error.span(), error.to_string()).to_compile_error(),
-                    ),
-                }
-                formattable.to_tokens(tokens, error_span);
+                let code = template_code_tokens(
+                    template,
+                    code_block_range.clone(),
+                    template_path,
+                    error_span,
+                )?;
+
+                scopes.current().extend(code);
+                formattable.to_tokens(scopes.current(), template_path, error_span);
             }
             TemplateExpression::Formattable(formattable) => {
-                formattable.to_tokens(tokens, error_span)
+                formattable.to_tokens(scopes.current(), template_path, error_span)
+            }
+            TemplateExpression::FormattableList(formattables) => {
+                for formattable in formattables {
+                    formattable.to_tokens(scopes.current(), template_path, error_span)
+                }
+            }
+            TemplateExpression::ScopeStart(template, header_range) => {
+                let header = template_code_tokens(
+                    template,
+                    header_range.clone(),
+                    template_path,
+                    error_span,
+                )?;
+
+                scopes.open(header, header_range.clone());
+            }
+            TemplateExpression::LoopStart(template, header_range, directive_range) => {
+                let header = template_code_tokens(
+                    template,
+                    header_range.clone(),
+                    template_path,
+                    error_span,
+                )?;
+
+                scopes.open_loop(header, directive_range.clone());
+            }
+            TemplateExpression::LoopSeparator(template, directive_range) => {
+                scopes.start_separator().map_err(|_| {
+                    TemplateError(
+                        directive_range.clone(),
+                        template_path,
+                        template,
+                        error::TemplateErrorKind::UnexpectedSeparator,
+                        error_span,
+                    )
+                })?;
+            }
+            TemplateExpression::LoopEnd(template, directive_range) => {
+                scopes.close_loop().map_err(|_| {
+                    TemplateError(
+                        directive_range.clone(),
+                        template_path,
+                        template,
+                        error::TemplateErrorKind::UnexpectedScopeEnd,
+                        error_span,
+                    )
+                })?;
+            }
+            TemplateExpression::ScopeEnd(template, closing_range) => {
+                scopes.close().map_err(|_| {
+                    TemplateError(
+                        closing_range.clone(),
+                        template_path,
+                        template,
+                        error::TemplateErrorKind::UnexpectedScopeEnd,
+                        error_span,
+                    )
+                })?;
+            }
+            TemplateExpression::ScopeContinuation(template, closing_range, header_range) => {
+                scopes.close().map_err(|_| {
+                    TemplateError(
+                        closing_range.clone(),
+                        template_path,
+                        template,
+                        error::TemplateErrorKind::UnexpectedScopeEnd,
+                        error_span,
+                    )
+                })?;
+
+                let header = template_code_tokens(
+                    template,
+                    header_range.clone(),
+                    template_path,
+                    error_span,
+                )?;
+
+                scopes.open(header, header_range.clone());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn code_tokens(code: &str) -> proc_macro2::TokenStream {
+    match proc_macro2::TokenStream::from_str(code) {
+        Ok(code) => code,
+        Err(error) => syn::Error::new(error.span(), error.to_string()).to_compile_error(),
+    }
+}
+
+fn template_code_tokens<'a>(
+    template: &'a str,
+    code_range: Range<usize>,
+    template_path: &'a Path,
+    error_span: proc_macro2::Span,
+) -> Result<proc_macro2::TokenStream, TemplateError<'a>> {
+    let code = &template[code_range.clone()];
+
+    proc_macro2::TokenStream::from_str(code).map_err(|_| {
+        let code_start = code_range.start + (code.len() - code.trim_start().len());
+
+        TemplateError(
+            code_start..(code_start + code.trim().len()),
+            template_path,
+            template,
+            error::TemplateErrorKind::InvalidCode,
+            error_span,
+        )
+    })
+}
+
+enum ScopeKind {
+    Block,
+    Loop(Option<proc_macro2::TokenStream>),
+}
+
+struct OpenScope {
+    opening_range: Range<usize>,
+    header: proc_macro2::TokenStream,
+    body: proc_macro2::TokenStream,
+    kind: ScopeKind,
+    bindings: HashSet<String>,
+}
+
+struct ScopeStack {
+    root: proc_macro2::TokenStream,
+    root_bindings: HashSet<String>,
+    open_scopes: Vec<OpenScope>,
+}
+
+impl ScopeStack {
+    fn new() -> Self {
+        Self {
+            root: proc_macro2::TokenStream::new(),
+            root_bindings: HashSet::new(),
+            open_scopes: Vec::new(),
+        }
+    }
+
+    fn bind(&mut self, code: &str) {
+        let bindings = match self.open_scopes.last_mut() {
+            Some(scope) => &mut scope.bindings,
+            None => &mut self.root_bindings,
+        };
+
+        binding_parsing::collect_bindings(code, bindings);
+    }
+
+    fn is_bound(&self, identifier: &str) -> bool {
+        self.root_bindings.contains(identifier)
+            || self
+                .open_scopes
+                .iter()
+                .any(|scope| scope.bindings.contains(identifier))
+    }
+
+    fn current(&mut self) -> &mut proc_macro2::TokenStream {
+        match self.open_scopes.last_mut() {
+            Some(OpenScope {
+                kind: ScopeKind::Loop(Some(separator)),
+                ..
+            }) => separator,
+            Some(scope) => &mut scope.body,
+            None => &mut self.root,
+        }
+    }
+
+    fn open(&mut self, header: proc_macro2::TokenStream, header_range: Range<usize>) {
+        self.open_scopes.push(OpenScope {
+            header,
+            opening_range: header_range.end..(header_range.end + 1),
+            body: proc_macro2::TokenStream::new(),
+            kind: ScopeKind::Block,
+            bindings: HashSet::new(),
+        });
+    }
+
+    fn open_loop(&mut self, header: proc_macro2::TokenStream, directive_range: Range<usize>) {
+        self.open_scopes.push(OpenScope {
+            header,
+            opening_range: directive_range,
+            body: proc_macro2::TokenStream::new(),
+            kind: ScopeKind::Loop(None),
+            bindings: HashSet::new(),
+        });
+    }
+
+    fn start_separator(&mut self) -> Result<(), ()> {
+        match self.open_scopes.last_mut() {
+            Some(OpenScope {
+                kind: kind @ ScopeKind::Loop(None),
+                ..
+            }) => {
+                *kind = ScopeKind::Loop(Some(proc_macro2::TokenStream::new()));
+                Ok(())
+            }
+            _ => Err(()),
+        }
+    }
+
+    fn close(&mut self) -> Result<(), ()> {
+        if !matches!(
+            self.open_scopes.last(),
+            Some(OpenScope {
+                kind: ScopeKind::Block,
+                ..
+            })
+        ) {
+            return Err(());
+        }
+
+        let OpenScope { header, body, .. } = self.open_scopes.pop().ok_or(())?;
+
+        self.current().extend(quote::quote! {
+            #header {
+                #body
             }
+        });
+
+        Ok(())
+    }
+
+    fn close_loop(&mut self) -> Result<(), ()> {
+        if !matches!(
+            self.open_scopes.last(),
+            Some(OpenScope {
+                kind: ScopeKind::Loop(_),
+                ..
+            })
+        ) {
+            return Err(());
+        }
+
+        let OpenScope {
+            header, body, kind, ..
+        } = self.open_scopes.pop().ok_or(())?;
+
+        let code = match kind {
+            ScopeKind::Loop(Some(separator)) => quote::quote! {
+                {
+                    let mut __remplate_is_first_iteration = true;
+                    #header {
+                        if !__remplate_is_first_iteration {
+                            #separator
+                        }
+                        __remplate_is_first_iteration = false;
+                        #body
+                    }
+                }
+            },
+            _ => quote::quote! {
+                #header {
+                    #body
+                }
+            },
+        };
+        self.current().extend(code);
+
+        Ok(())
+    }
+
+    fn is_root(&self) -> bool {
+        self.open_scopes.is_empty()
+    }
+
+    fn into_tokens(self) -> Result<proc_macro2::TokenStream, Range<usize>> {
+        match self.open_scopes.last() {
+            Some(unclosed_scope) => Err(unclosed_scope.opening_range.clone()),
+            None => Ok(self.root),
         }
     }
 }
@@ -58,7 +402,158 @@ impl<'a> TryFrom<(&'a str, Range<usize>)> for TemplateExpression<'a> {
     ) -> Result<Self, Self::Error> {
         let code_block = &template[code_block_range.clone()];
 
-        match code_block.rfind(';') {
+        if remplate_parser::is_comment(code_block) {
+            return Ok(TemplateExpression::Comment);
+        }
+
+        let scope_code = code_block.trim();
+        let scope_code_start =
+            code_block_range.start + (code_block.len() - code_block.trim_start().len());
+        let scope_code_end = scope_code_start + scope_code.len();
+
+        if let Some(include_path) = scope_code.strip_prefix("include_static") {
+            if let Ok(include_path) = syn::parse_str::<syn::LitStr>(include_path) {
+                return Ok(TemplateExpression::IncludeStatic(
+                    scope_code_start..scope_code_end,
+                    include_path.value(),
+                ));
+            }
+        }
+
+        if let Some(key) = scope_code
+            .strip_prefix("t!(")
+            .and_then(|key| key.strip_suffix(')'))
+        {
+            if let Ok(key) = syn::parse_str::<syn::LitStr>(key) {
+                return Ok(TemplateExpression::Translation(
+                    scope_code_start..scope_code_end,
+                    key.value(),
+                ));
+            }
+        }
+
+        if let Some(include) = scope_code.strip_prefix("include") {
+            if let Some((include_path, bindings)) = parse_include(include) {
+                return Ok(TemplateExpression::Include(
+                    scope_code_start..scope_code_end,
+                    include_path,
+                    bindings,
+                ));
+            }
+        }
+
+        if let Some(directive) = scope_code
+            .strip_prefix('%')
+            .and_then(|directive| directive.strip_suffix('%'))
+        {
+            let directive_start =
+                scope_code_start + 1 + (directive.len() - directive.trim_start().len());
+            let directive = directive.trim();
+
+            if let Some(escape_name) = directive.strip_prefix("escape ") {
+                return Ok(TemplateExpression::EscapeStart(
+                    scope_code_start..scope_code_end,
+                    escape_name.trim(),
+                ));
+            } else if directive == "endescape" {
+                return Ok(TemplateExpression::EscapeEnd(
+                    scope_code_start..scope_code_end,
+                ));
+            } else if let Some(region_name) = directive.strip_prefix("region ") {
+                return Ok(TemplateExpression::RegionStart(
+                    scope_code_start..scope_code_end,
+                    region_name.trim(),
+                ));
+            } else if directive == "endregion" {
+                return Ok(TemplateExpression::RegionEnd(
+                    scope_code_start..scope_code_end,
+                ));
+            } else if let Some(base_path) = directive.strip_prefix("extends ") {
+                if let Ok(base_path) = syn::parse_str::<syn::LitStr>(base_path) {
+                    return Ok(TemplateExpression::Extends(
+                        scope_code_start..scope_code_end,
+                        base_path.value(),
+                    ));
+                }
+            } else if let Some(block_name) = directive.strip_prefix("block ") {
+                return Ok(TemplateExpression::BlockStart(
+                    scope_code_start..scope_code_end,
+                    block_name.trim(),
+                ));
+            } else if directive == "endblock" {
+                return Ok(TemplateExpression::BlockEnd(
+                    scope_code_start..scope_code_end,
+                ));
+            } else if directive == "offset" {
+                return Ok(TemplateExpression::Offset(scope_code_start..scope_code_end));
+            } else if let Some(binding) = directive.strip_prefix("set ") {
+                let binding_start = directive_start + (directive.len() - binding.len());
+
+                return Ok(TemplateExpression::Set(
+                    template,
+                    binding_start..(binding_start + binding.len()),
+                ));
+            } else if directive.starts_with("for ") {
+                return Ok(TemplateExpression::LoopStart(
+                    template,
+                    directive_start..(directive_start + directive.len()),
+                    scope_code_start..scope_code_end,
+                ));
+            } else if directive == "sep" {
+                return Ok(TemplateExpression::LoopSeparator(
+                    template,
+                    scope_code_start..scope_code_end,
+                ));
+            } else if directive == "endfor" {
+                return Ok(TemplateExpression::LoopEnd(
+                    template,
+                    scope_code_start..scope_code_end,
+                ));
+            }
+        }
+
+        if let Some(expression) = scope_code.strip_prefix('=') {
+            if !expression.trim().is_empty() {
+                return Ok(TemplateExpression::Formattable(Formattable::from((
+                    template,
+                    (scope_code_start + 1)..scope_code_end,
+                ))));
+            }
+        }
+
+        if code_block.starts_with('[') {
+            if let Some(formattables) = formattable_list(template, scope_code_start..scope_code_end)
+            {
+                return Ok(TemplateExpression::FormattableList(formattables));
+            }
+        }
+
+        if let Some(continuation) = scope_code.strip_prefix('}') {
+            let closing_range = scope_code_start..(scope_code_start + 1);
+
+            if continuation.trim().is_empty() {
+                return Ok(TemplateExpression::ScopeEnd(template, closing_range));
+            } else if scope_code.ends_with('{') {
+                return Ok(TemplateExpression::ScopeContinuation(
+                    template,
+                    closing_range,
+                    (scope_code_start + 1)..(scope_code_end - 1),
+                ));
+            }
+        } else if scope_code.ends_with('{') {
+            return Ok(TemplateExpression::ScopeStart(
+                template,
+                scope_code_start..(scope_code_end - 1),
+            ));
+        }
+
+        match filter_parsing::statement_end_position(code_block) {
+            Some(position) if filter_parsing::is_statement(&code_block[(position + 1)..]) => {
+                Ok(TemplateExpression::CodeBlock(template, code_block_range))
+            }
+            None if filter_parsing::is_statement(code_block) => {
+                Ok(TemplateExpression::CodeBlock(template, code_block_range))
+            }
             Some(position) => match code_block[(position + 1)..].trim() {
                 "" => Ok(TemplateExpression::CodeBlock(
                     template,
@@ -76,7 +571,7 @@ impl<'a> TryFrom<(&'a str, Range<usize>)> for TemplateExpression<'a> {
                 )),
             },
             None => match code_block.trim() {
-                "" => Err(()),
+                "" => Ok(TemplateExpression::Empty),
                 _ => Ok(TemplateExpression::Formattable(Formattable::from((
                     template,
                     code_block_range,
@@ -86,47 +581,201 @@ impl<'a> TryFrom<(&'a str, Range<usize>)> for TemplateExpression<'a> {
     }
 }
 
+fn formattable_list(template: &str, list_range: Range<usize>) -> Option<Vec<Formattable<'_>>> {
+    let (list_range, formatting_range) =
+        match filter_parsing::format_spec_position(&template[list_range.clone()]) {
+            Some(position) => (
+                list_range.start..(list_range.start + position),
+                Some((list_range.start + position)..list_range.end),
+            ),
+            None => (list_range, None),
+        };
+
+    let element_ranges = filter_parsing::list_element_ranges(&template[list_range.clone()])?;
+
+    Some(
+        element_ranges
+            .into_iter()
+            .map(|element_range| {
+                let mut formattable = Formattable::from((
+                    template,
+                    (list_range.start + element_range.start)
+                        ..(list_range.start + element_range.end),
+                ));
+                if formattable.formatting_range.is_none() {
+                    formattable.formatting_range = formatting_range.clone();
+                }
+
+                formattable
+            })
+            .collect(),
+    )
+}
+
 struct Formattable<'a> {
     template: &'a str,
     expression_range: Range<usize>,
     formatting_range: Option<Range<usize>>,
+    is_optional: bool,
+    filters: Vec<filter_parsing::Filter>,
+    escape: Option<&'a str>,
+    is_runtime_escaped: bool,
+    is_span_recorded: bool,
+    is_annotated: bool,
+    is_context_field: bool,
 }
 
 impl<'a> From<(&'a str, Range<usize>)> for Formattable<'a> {
     fn from((template, expression_range): (&'a str, Range<usize>)) -> Self {
         let format_expression = &template[expression_range.clone()];
 
-        if let Some(position) = format_expression.find(':') {
-            Formattable {
+        let (expression_range, formatting_range) =
+            match filter_parsing::format_spec_position(format_expression) {
+                Some(position) => (
+                    expression_range.start..(expression_range.start + position),
+                    Some((expression_range.start + position)..expression_range.end),
+                ),
+                None => (expression_range, None),
+            };
+
+        let filter_parsing::FilterParseResult {
+            expression_end,
+            filters,
+        } = filter_parsing::parse_filters(&template[expression_range.clone()]);
+        let filters = filters
+            .into_iter()
+            .map(|filter| filter.offset(expression_range.start))
+            .collect();
+        let expression_range = expression_range.start..(expression_range.start + expression_end);
+
+        let expression = template[expression_range.clone()].trim_end();
+
+        match expression.strip_suffix('?') {
+            Some(optional_expression) => Formattable {
                 template,
-                expression_range: expression_range.start..(expression_range.start + position),
-                formatting_range: Some((expression_range.start + position)..expression_range.end),
-            }
-        } else {
-            Formattable {
+                expression_range: expression_range.start
+                    ..(expression_range.start + optional_expression.len()),
+                formatting_range,
+                is_optional: true,
+                filters,
+                escape: None,
+                is_runtime_escaped: false,
+                is_span_recorded: false,
+                is_annotated: false,
+                is_context_field: false,
+            },
+            None => Formattable {
                 template,
                 expression_range,
-                formatting_range: None,
-            }
+                formatting_range,
+                is_optional: false,
+                filters,
+                escape: None,
+                is_runtime_escaped: false,
+                is_span_recorded: false,
+                is_annotated: false,
+                is_context_field: false,
+            },
         }
     }
 }
 
 impl<'a> Formattable<'a> {
-    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream, error_span: proc_macro2::Span) {
-        tokens.extend(match self {
+    fn is_escapable(&self) -> bool {
+        let expression = &self.template[self.expression_range.clone()];
+
+        let is_escaping_filter = |filter: &filter_parsing::Filter| {
+            let name = &self.template[filter.name_range.clone()];
+
+            matches!(name, "attr" | "raw") || filter_parsing::ESCAPE_NAMES.contains(&name)
+        };
+
+        !self.filters.iter().any(is_escaping_filter)
+            && filter_parsing::helper_call_arguments(expression, "attr").is_none()
+    }
+
+    fn is_literal(&self) -> bool {
+        let expression = self.template[self.expression_range.clone()].trim();
+
+        self.filters.is_empty()
+            && !self.is_optional
+            && syn::parse_str::<syn::Lit>(expression).is_ok()
+    }
+
+    fn set_escape(&mut self, escape: Option<&'a str>) {
+        if self.is_escapable() {
+            self.escape = escape;
+        }
+    }
+
+    fn to_tokens(
+        &self,
+        tokens: &mut proc_macro2::TokenStream,
+        template_path: &Path,
+        error_span: proc_macro2::Span,
+    ) {
+        let (format_part, expression, spec_arguments, format_trait) = match self {
             Formattable {
                 template,
                 expression_range,
                 formatting_range: Some(formatting_range),
+                ..
             } => {
-                let format_part = format!("{{{}}}", &template[formatting_range.clone()]);
+                let format_spec = &template[(formatting_range.start + 1)..formatting_range.end];
+                let format_spec_start = formatting_range.start
+                    + 1
+                    + (format_spec.len() - format_spec.trim_start().len());
+                let format_spec = format_spec.trim();
+
+                let argument_ranges = format_spec_parsing::spec_argument_ranges(format_spec);
+                let mut validated_spec = format_spec.to_string();
+                for argument_range in &argument_ranges {
+                    validated_spec
+                        .replace_range(argument_range.clone(), &"_".repeat(argument_range.len()));
+                }
+
+                if let Some(position) =
+                    format_spec_parsing::invalid_format_spec_position(&validated_spec)
+                {
+                    tokens.extend(
+                        TemplateError(
+                            (format_spec_start + position)..(format_spec_start + format_spec.len()),
+                            template_path,
+                            template,
+                            error::TemplateErrorKind::InvalidFormatSpec,
+                            error_span,
+                        )
+                        .abortion_error(),
+                    );
+                    return;
+                }
+
+                let mut spec = String::new();
+                let mut spec_arguments = proc_macro2::TokenStream::new();
+                let mut last_position = 0;
+                for (index, argument_range) in argument_ranges.iter().enumerate() {
+                    let name = proc_macro2::Ident::new(
+                        &format!("__remplate_spec_argument_{}", index),
+                        proc_macro2::Span::call_site(),
+                    );
+                    let argument = code_tokens(
+                        &format_spec[(argument_range.start + 1)..(argument_range.end - 1)],
+                    );
+
+                    spec.push_str(&format_spec[last_position..argument_range.start]);
+                    spec.push_str(&name.to_string());
+                    spec_arguments.extend(quote::quote! { , #name = (#argument) });
+                    last_position = argument_range.end;
+                }
+                spec.push_str(&format_spec[last_position..]);
+
+                let format_part = format!("{{:{}}}", spec);
                 let expression_fragment = &template[expression_range.clone()];
 
                 let expression = if expression_fragment.trim().is_empty() {
                     TemplateError(
                         formatting_range.clone(),
-                        TEMPLATE_PATH.get().expect(INVALID_STATE_MESSAGE),
+                        template_path,
                         template,
                         error::TemplateErrorKind::MissingValue,
                         error_span,
@@ -141,111 +790,1119 @@ impl<'a> Formattable<'a> {
                     }
                 };
 
-                quote::quote! {
-                    f.write_fmt(format_args!(#format_part, #expression))?;
-                }
+                let format_trait = format_spec_parsing::format_trait(&validated_spec);
+
+                (format_part, expression, spec_arguments, format_trait)
             }
             Formattable {
                 template,
                 expression_range,
                 formatting_range: None,
+                ..
             } => {
                 let expression_fragment = &template[expression_range.clone()];
 
-                let expression = match proc_macro2::TokenStream::from_str(expression_fragment) {
-                    Ok(code) => code,
-                    Err(error) => syn::Error::new(
-                        error.span(),
-                        format!("Invalid expression - '{}'", expression_fragment),
-                    )
-                    .to_compile_error(),
-                };
+                let expression =
+                    match filter_parsing::helper_call_arguments(expression_fragment, "attr") {
+                        Some(arguments_range) => {
+                            let arguments = code_tokens(&expression_fragment[arguments_range]);
+                            quote::quote! { ::remplate::filters::attribute(#arguments) }
+                        }
+                        None => match proc_macro2::TokenStream::from_str(expression_fragment) {
+                            Ok(code) => code,
+                            Err(error) => syn::Error::new(
+                                error.span(),
+                                format!("Invalid expression - '{}'", expression_fragment),
+                            )
+                            .to_compile_error(),
+                        },
+                    };
+
+                (
+                    String::from("{}"),
+                    expression,
+                    proc_macro2::TokenStream::new(),
+                    Some("display"),
+                )
+            }
+        };
+
+        let expression = match self.is_context_field {
+            true => {
+                let field = code_tokens(self.template[self.expression_range.clone()].trim());
+                quote::quote! { __remplate_context.#field }
+            }
+            false => expression,
+        };
+
+        let (value, expression) = match self.is_optional {
+            true => (quote::quote! { value }, Some(expression)),
+            false => (expression, None),
+        };
+        let value = self.filtered_value(value, format_trait);
+
+        let is_escaped = self.escape.is_some() || self.is_runtime_escaped;
+        let (format_part, value, spec_arguments) = match (is_escaped, &self.formatting_range) {
+            (true, Some(_)) => (
+                String::from("{}"),
+                self.escaped_value(
+                    quote::quote! { format_args!(#format_part, #value #spec_arguments) },
+                ),
+                proc_macro2::TokenStream::new(),
+            ),
+            _ => (format_part, self.escaped_value(value), spec_arguments),
+        };
+
+        let write = match expression {
+            Some(expression) => quote::quote! {
+                if let ::core::option::Option::Some(value) = &(#expression) {
+                    ::core::fmt::Write::write_fmt(f, format_args!(#format_part, #value #spec_arguments))?;
+                }
+            },
+            None => quote::quote! {
+                ::core::fmt::Write::write_fmt(f, format_args!(#format_part, #value #spec_arguments))?;
+            },
+        };
+
+        let write = match self.is_span_recorded {
+            true => quote::quote! {
+                {
+                    let __remplate_span_start = f.position();
+                    #write
+                    __remplate_spans.push(__remplate_span_start..f.position());
+                }
+            },
+            false => write,
+        };
+
+        tokens.extend(match self.is_annotated {
+            true => {
+                let annotation = format!(
+                    "<!--{{{}}}-->",
+                    self.template[self.expression_range.clone()].trim()
+                );
 
                 quote::quote! {
-                    f.write_fmt(format_args!("{}", #expression))?;
+                    ::core::fmt::Write::write_str(f, #annotation)?;
+                    #write
+                    ::core::fmt::Write::write_str(f, "<!--/-->")?;
                 }
             }
+            false => write,
         })
     }
-}
-
-fn create_code(
-    template: &str,
-    error_span: proc_macro2::Span,
-) -> Result<(usize, proc_macro2::TokenStream), TemplateError> {
-    let template_parsing::ParseResult {
-        code_block_fragment_ranges,
-        template_fragment_ranges,
-    } = template_parsing::parse_template(template).map_err(|error| {
-        let template_path = TEMPLATE_PATH.get().expect(INVALID_STATE_MESSAGE);
-        error.into(template_path, template, error_span)
-    })?;
 
-    let estimated_template_size = (template_fragment_ranges
-        .iter()
-        .fold(0, |acc, fragment| acc + fragment.len()))
-        + (code_block_fragment_ranges.len() * core::mem::size_of::<i64>() * 2);
+    fn filtered_value(
+        &self,
+        value: proc_macro2::TokenStream,
+        format_trait: Option<&str>,
+    ) -> proc_macro2::TokenStream {
+        let value = match (format_trait, self.filters.is_empty()) {
+            (Some(format_trait), true) => {
+                let name = proc_macro2::Ident::new(format_trait, proc_macro2::Span::call_site());
+                quote::quote! { ::remplate::format_traits::#name(&(#value)) }
+            }
+            _ => value,
+        };
 
-    let mut code = proc_macro2::TokenStream::new();
+        self.filters.iter().fold(value, |value, filter| {
+            let name = proc_macro2::Ident::new(
+                &self.template[filter.name_range.clone()],
+                proc_macro2::Span::call_site(),
+            );
 
-    {
-        let first_template_fragment = &template[template_fragment_ranges.first().unwrap().clone()];
-        code.extend(quote::quote! {
-            f.write_str(#first_template_fragment)?;
-        });
+            match &filter.arguments_range {
+                Some(arguments_range) => {
+                    let arguments = code_tokens(&self.template[arguments_range.clone()]);
+                    quote::quote! { ::remplate::filters::#name(&(#value), #arguments) }
+                }
+                None => quote::quote! { ::remplate::filters::#name(&(#value)) },
+            }
+        })
     }
 
-    if let Some(block_range) = code_block_fragment_ranges.first() {
-        if let Ok(expression) = TemplateExpression::try_from((template, block_range.clone())) {
-            expression.to_tokens(&mut code, error_span);
+    fn escaped_value(&self, value: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        match self.escape {
+            Some(escape) => {
+                let name = proc_macro2::Ident::new(escape, proc_macro2::Span::call_site());
+                quote::quote! { ::remplate::filters::#name(&(#value)) }
+            }
+            None if self.is_runtime_escaped => {
+                quote::quote! { ::remplate::filters::html_if(&(#value), __remplate_escape) }
+            }
+            None => value,
         }
     }
+}
 
-    for (template_fragment_range, block_range) in
-        iter::zip(&template_fragment_ranges, &code_block_fragment_ranges).skip(1)
-    {
-        let template_fragment = &template[template_fragment_range.clone()];
-        code.extend(quote::quote! {
-            f.write_str(#template_fragment)?;
+fn write_template_fragment(tokens: &mut proc_macro2::TokenStream, template_fragment: &str) {
+    if !template_fragment.is_empty() {
+        tokens.extend(quote::quote! {
+            ::core::fmt::Write::write_str(f, #template_fragment)?;
         });
-
-        if let Ok(expression) = TemplateExpression::try_from((template, block_range.clone())) {
-            expression.to_tokens(&mut code, error_span);
-        }
     }
+}
 
-    if let Some(template_fragment_range) = template_fragment_ranges.last() {
-        let template_fragment = &template[template_fragment_range.clone()];
-        code.extend(quote::quote! {
-            f.write_str(#template_fragment)?;
-        });
-    }
+const MINIMUM_ESTIMATED_SIZE: usize = 16;
+
+const MAXIMUM_INCLUDE_DEPTH: usize = 16;
 
-    Ok((estimated_template_size, code))
+const RESERVED_REGION_NAMES: [&str; 15] = [
+    "and_flush",
+    "appending",
+    "arc",
+    "buffered",
+    "ctx",
+    "debug",
+    "escaped",
+    "heapless",
+    "to_bytes_mut",
+    "to_fmt_limited",
+    "to_mmap",
+    "to_writer",
+    "with_capacity",
+    "with_region_info",
+    "with_spans",
+];
+
+#[derive(Default, Clone, Copy)]
+struct TemplateOptions<'a> {
+    trim_blocks: bool,
+    lstrip_blocks: bool,
+    has_context_field: bool,
+    runtime_escape: bool,
+    lint: bool,
+    record_spans: bool,
+    annotate_expressions: bool,
+    region: Option<usize>,
+    track_regions: bool,
+    minify: bool,
+    contextual_escape: bool,
+    escape: Option<&'static str>,
+    escape_by_extension: bool,
+    messages: Option<&'a HashMap<String, String>>,
+    include_depth: usize,
 }
 
-fn create_include_bytes(file_path: &PathBuf) -> proc_macro2::TokenStream {
-    let file_path = file_path.to_string_lossy();
+fn extension_escape(template_path: &Path) -> Option<&'static str> {
+    match template_path.extension()?.to_str()? {
+        "xml" => Some("xml"),
+        "json" => Some("json"),
+        "csv" => Some("csv"),
+        "sql" => Some("sql"),
+        _ => None,
+    }
+}
 
-    quote::quote! {
-        ::core::include_bytes!(#file_path);
+fn literal_interpolation_warning(
+    literal: &str,
+    error_span: proc_macro2::Span,
+) -> proc_macro2::TokenStream {
+    let note = format!(
+        "The interpolation `{}` is a literal and can be written into the template directly",
+        literal
+    );
+
+    quote::quote_spanned! { error_span =>
+        {
+            #[deprecated(note = #note)]
+            struct LiteralInterpolation;
+            let _ = LiteralInterpolation;
+        }
     }
 }
 
-#[derive(Debug)]
-enum PathCanonicalizationError {
-    CargoManifestDirVariable(std::env::VarError),
-    IoError {
-        path: PathBuf,
-        source: std::io::Error,
-    },
+fn is_loop_header(header: &str) -> bool {
+    let header = header.trim_start();
+
+    ["for", "while", "loop"].iter().any(|keyword| {
+        header.starts_with(keyword)
+            && header[keyword.len()..]
+                .starts_with(|character: char| character.is_whitespace() || character == '{')
+    }) || header == "loop"
 }
 
-impl std::fmt::Display for PathCanonicalizationError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+fn strip_leading_newline(template_fragment: &str) -> &str {
+    template_fragment
+        .strip_prefix("\r\n")
+        .or_else(|| template_fragment.strip_prefix('\n'))
+        .unwrap_or(template_fragment)
+}
+
+fn is_indentation(template_fragment: &str) -> bool {
+    template_fragment
+        .chars()
+        .all(|character| matches!(character, ' ' | '\t'))
+}
+
+fn strip_trailing_indentation(template_fragment: &str, is_template_start: bool) -> &str {
+    match template_fragment.rfind('\n') {
+        Some(position) if is_indentation(&template_fragment[(position + 1)..]) => {
+            &template_fragment[..(position + 1)]
+        }
+        None if is_template_start && is_indentation(template_fragment) => "",
+        _ => template_fragment,
+    }
+}
+
+type ParseCache = HashMap<PathBuf, (u64, Arc<remplate_parser::ParseResult>)>;
+
+fn parse_template_cached(
+    template: &str,
+    template_path: &Path,
+) -> Result<Arc<remplate_parser::ParseResult>, remplate_parser::TemplateParseError> {
+    static PARSE_CACHE: OnceLock<Mutex<ParseCache>> = OnceLock::new();
+
+    let mut hasher = DefaultHasher::new();
+    template.hash(&mut hasher);
+    let template_hash = hasher.finish();
+
+    let mut cache = PARSE_CACHE
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+    match cache.get(template_path) {
+        Some((cached_hash, parse_result)) if *cached_hash == template_hash => {
+            Ok(Arc::clone(parse_result))
+        }
+        _ => {
+            let parse_result = Arc::new(remplate_parser::parse_template(template)?);
+            cache.insert(
+                template_path.to_path_buf(),
+                (template_hash, Arc::clone(&parse_result)),
+            );
+
+            Ok(parse_result)
+        }
+    }
+}
+
+fn estimate_size(template: &str, parse_result: &remplate_parser::ParseResult) -> usize {
+    let static_size = parse_result
+        .template_fragment_ranges
+        .iter()
+        .fold(0, |acc, fragment| acc + fragment.len());
+    let rendering_code_blocks = parse_result
+        .code_block_fragment_ranges
+        .iter()
+        .filter(|code_block| !remplate_parser::is_comment(&template[(*code_block).clone()]))
+        .count();
+
+    static_size + (rendering_code_blocks * core::mem::size_of::<i64>() * 2)
+}
+
+fn check_static_only<'a>(
+    template: &'a str,
+    template_path: &'a Path,
+    error_span: proc_macro2::Span,
+) -> Result<(), TemplateError<'a>> {
+    let parse_result = parse_template_cached(template, template_path)
+        .map_err(|error| TemplateError::from((error, template_path, template, error_span)))?;
+
+    let dynamic_block = parse_result
+        .code_block_fragment_ranges
+        .iter()
+        .find(|block_range| {
+            let code_block = &template[(*block_range).clone()];
+            !remplate_parser::is_comment(code_block) && !code_block.trim().is_empty()
+        })
+        .cloned();
+
+    match dynamic_block {
+        Some(block_range) => Err(TemplateError(
+            (block_range.start - 1)..(block_range.end + 1),
+            template_path,
+            template,
+            error::TemplateErrorKind::DynamicContent,
+            error_span,
+        )),
+        None => Ok(()),
+    }
+}
+
+fn create_code<'a>(
+    template: &'a str,
+    template_path: &'a Path,
+    options: TemplateOptions,
+    error_span: proc_macro2::Span,
+) -> Result<RemplateData, TemplateError<'a>> {
+    let parse_result = parse_template_cached(template, template_path)
+        .map_err(|error| TemplateError::from((error, template_path, template, error_span)))?;
+
+    if let Some((extends_range, base_path)) = extended_path(template, &parse_result) {
+        return extend_template(
+            template,
+            template_path,
+            &parse_result,
+            (extends_range, &base_path),
+            options,
+            error_span,
+        );
+    }
+
+    let mut estimated_template_size = estimate_size(template, &parse_result);
+
+    let remplate_parser::ParseResult {
+        code_block_fragment_ranges,
+        template_fragment_ranges,
+    } = &*parse_result;
+
+    let mut scopes = ScopeStack::new();
+    let mut included_paths = Vec::new();
+    let mut template_fragment_ranges = template_fragment_ranges.iter();
+    let mut template_fragment = template_fragment_ranges
+        .next()
+        .map(|template_fragment_range| &template[template_fragment_range.clone()])
+        .unwrap_or_default();
+    let mut is_first_template_fragment = true;
+    let mut escapes = Vec::new();
+    let mut regions: Vec<(Range<usize>, usize)> = Vec::new();
+    let mut region_names = Vec::new();
+    let mut uses_offset = false;
+    let mut minimum_size = 0;
+    let mut maximum_size = Some(0);
+    let mut preformatted_element = None;
+    let mut static_text = String::new();
+    let mut html_context = html_context::HtmlContext::default();
+    let default_escape = match options.escape_by_extension {
+        true => options.escape.or_else(|| extension_escape(template_path)),
+        false => options.escape,
+    }
+    .filter(|escape| !(options.runtime_escape && *escape == "html"));
+
+    for block_range in code_block_fragment_ranges {
+        let code_block = &template[block_range.clone()];
+        if !remplate_parser::is_comment(code_block) {
+            if let Some(position) = remplate_parser::find_unbalanced_delimiter(code_block) {
+                let position = block_range.start + position;
+
+                return Err(TemplateError(
+                    position..(position + 1),
+                    template_path,
+                    template,
+                    error::TemplateErrorKind::UnbalancedDelimiter,
+                    error_span,
+                ));
+            }
+        }
+
+        let is_writing = match options.region {
+            Some(region) => regions.iter().any(|(_, index)| *index == region),
+            None => true,
+        };
+
+        let mut expression = match TemplateExpression::try_from((template, block_range.clone())) {
+            Ok(
+                TemplateExpression::Formattable(_)
+                | TemplateExpression::FormattableList(_)
+                | TemplateExpression::Include(..)
+                | TemplateExpression::IncludeStatic(..)
+                | TemplateExpression::Offset(..)
+                | TemplateExpression::Translation(..),
+            ) if !is_writing => Ok(TemplateExpression::Comment),
+            Ok(TemplateExpression::CodeBlockWithFormattable((template, code_block_range), _))
+                if !is_writing =>
+            {
+                Ok(TemplateExpression::CodeBlock(template, code_block_range))
+            }
+            expression => expression,
+        };
+        let is_scope_opening = matches!(
+            expression,
+            Ok(TemplateExpression::ScopeStart(..)
+                | TemplateExpression::LoopStart(..)
+                | TemplateExpression::ScopeContinuation(..))
+        );
+        if !is_scope_opening {
+            scopes.bind(code_block);
+        }
+        html_context = html_context.advance(template_fragment);
+        let contextual_escape = options.contextual_escape.then(|| html_context.escape());
+
+        let formattables = match &mut expression {
+            Ok(TemplateExpression::Formattable(formattable))
+            | Ok(TemplateExpression::CodeBlockWithFormattable(_, formattable)) => vec![formattable],
+            Ok(TemplateExpression::FormattableList(formattables)) => {
+                formattables.iter_mut().collect()
+            }
+            _ => Vec::new(),
+        };
+        for formattable in formattables {
+            formattable.set_escape(
+                escapes
+                    .last()
+                    .map(|(_, escape)| *escape)
+                    .or(contextual_escape)
+                    .or(default_escape),
+            );
+            formattable.is_span_recorded = options.record_spans;
+            formattable.is_annotated = options.annotate_expressions;
+            formattable.is_runtime_escaped = options.runtime_escape
+                && formattable.escape.is_none()
+                && formattable.is_escapable();
+
+            let value = formattable.template[formattable.expression_range.clone()].trim();
+            formattable.is_context_field = options.has_context_field
+                && binding_parsing::is_identifier(value)
+                && !value.starts_with(char::is_uppercase)
+                && !scopes.is_bound(value);
+
+            if options.lint && formattable.is_literal() {
+                scopes
+                    .current()
+                    .extend(literal_interpolation_warning(value, error_span));
+            }
+        }
+        let is_block_without_output = matches!(
+            expression,
+            Ok(TemplateExpression::CodeBlock(..)
+                | TemplateExpression::ScopeStart(..)
+                | TemplateExpression::ScopeEnd(..)
+                | TemplateExpression::ScopeContinuation(..)
+                | TemplateExpression::EscapeStart(..)
+                | TemplateExpression::EscapeEnd(..)
+                | TemplateExpression::RegionStart(..)
+                | TemplateExpression::RegionEnd(..)
+                | TemplateExpression::BlockStart(..)
+                | TemplateExpression::BlockEnd(..)
+                | TemplateExpression::Set(..)
+                | TemplateExpression::LoopStart(..)
+                | TemplateExpression::LoopSeparator(..)
+                | TemplateExpression::LoopEnd(..))
+        );
+
+        if options.lstrip_blocks && is_block_without_output {
+            template_fragment =
+                strip_trailing_indentation(template_fragment, is_first_template_fragment);
+        }
+        let minified_fragment = options
+            .minify
+            .then(|| minify::minify_fragment(template_fragment, &mut preformatted_element));
+        if is_writing {
+            let written_fragment = minified_fragment.as_deref().unwrap_or(template_fragment);
+            let fragment_size = written_fragment.len();
+            let is_unbounded = match &expression {
+                Ok(
+                    TemplateExpression::Formattable(_)
+                    | TemplateExpression::FormattableList(_)
+                    | TemplateExpression::CodeBlockWithFormattable(..)
+                    | TemplateExpression::Offset(_)
+                    | TemplateExpression::LoopStart(..),
+                ) => true,
+                Ok(TemplateExpression::ScopeStart(template, header_range)) => {
+                    let header = &template[header_range.clone()];
+
+                    is_loop_header(header) || binding_parsing::mentions(header, "f")
+                }
+                Ok(
+                    TemplateExpression::CodeBlock(template, code_range)
+                    | TemplateExpression::ScopeContinuation(template, _, code_range),
+                ) => binding_parsing::mentions(&template[code_range.clone()], "f"),
+                _ => false,
+            };
+
+            if scopes.is_root() {
+                minimum_size += fragment_size;
+            }
+            maximum_size = match is_unbounded {
+                true => None,
+                false => maximum_size.map(|size| size + fragment_size),
+            };
+
+            write_template_fragment(scopes.current(), written_fragment);
+            static_text.push_str(written_fragment);
+        }
+
+        match expression {
+            Ok(TemplateExpression::IncludeStatic(include_range, include_path)) => {
+                let (included_path, included_content) =
+                    read_included_file(template_path, &include_path).map_err(|_| {
+                        TemplateError(
+                            include_range,
+                            template_path,
+                            template,
+                            error::TemplateErrorKind::UnreadableInclude,
+                            error_span,
+                        )
+                    })?;
+
+                estimated_template_size += included_content.len();
+                if scopes.is_root() {
+                    minimum_size += included_content.len();
+                }
+                maximum_size = maximum_size.map(|size| size + included_content.len());
+                write_template_fragment(scopes.current(), &included_content);
+                static_text.push_str(&included_content);
+                included_paths.push(included_path);
+            }
+            Ok(TemplateExpression::Include(include_range, include_path, bindings)) => {
+                let (included_path, included_content) =
+                    read_included_file(template_path, &include_path).map_err(|_| {
+                        TemplateError(
+                            include_range.clone(),
+                            template_path,
+                            template,
+                            error::TemplateErrorKind::UnreadableInclude,
+                            error_span,
+                        )
+                    })?;
+
+                if options.include_depth >= MAXIMUM_INCLUDE_DEPTH {
+                    return Err(TemplateError(
+                        include_range,
+                        template_path,
+                        template,
+                        error::TemplateErrorKind::IncludeDepth,
+                        error_span,
+                    ));
+                }
+
+                let included_options = TemplateOptions {
+                    region: None,
+                    track_regions: false,
+                    include_depth: options.include_depth + 1,
+                    ..options
+                };
+                let included_code = match create_code(
+                    &included_content,
+                    &included_path,
+                    included_options,
+                    error_span,
+                ) {
+                    Ok(RemplateData {
+                        estimated_template_size: included_size,
+                        remplate_code,
+                        included_paths: nested_included_paths,
+                        uses_offset: included_uses_offset,
+                        size_hint: (included_minimum_size, included_maximum_size),
+                        ..
+                    }) => {
+                        if scopes.is_root() {
+                            minimum_size += included_minimum_size;
+                        }
+                        maximum_size = maximum_size
+                            .zip(included_maximum_size)
+                            .map(|(size, included_size)| size + included_size);
+                        estimated_template_size += included_size;
+                        included_paths.extend(nested_included_paths);
+                        uses_offset |= included_uses_offset;
+                        remplate_code
+                    }
+                    Err(error) => error.abortion_error(),
+                };
+
+                scopes.current().extend(quote::quote! {
+                    {
+                        #bindings
+                        #included_code
+                    }
+                });
+                included_paths.push(included_path);
+            }
+            Ok(TemplateExpression::EscapeStart(escape_range, escape)) => {
+                if !filter_parsing::ESCAPE_NAMES.contains(&escape) {
+                    return Err(TemplateError(
+                        escape_range,
+                        template_path,
+                        template,
+                        error::TemplateErrorKind::UnknownEscape,
+                        error_span,
+                    ));
+                }
+
+                escapes.push((escape_range, escape));
+            }
+            Ok(TemplateExpression::EscapeEnd(escape_range)) => {
+                if escapes.pop().is_none() {
+                    return Err(TemplateError(
+                        escape_range,
+                        template_path,
+                        template,
+                        error::TemplateErrorKind::UnexpectedScopeEnd,
+                        error_span,
+                    ));
+                }
+            }
+            Ok(TemplateExpression::RegionStart(region_range, region_name)) => {
+                if !binding_parsing::is_identifier(region_name) {
+                    return Err(TemplateError(
+                        region_range,
+                        template_path,
+                        template,
+                        error::TemplateErrorKind::InvalidRegionName,
+                        error_span,
+                    ));
+                }
+
+                if region_names.contains(&region_name) {
+                    return Err(TemplateError(
+                        region_range,
+                        template_path,
+                        template,
+                        error::TemplateErrorKind::DuplicateRegion,
+                        error_span,
+                    ));
+                }
+
+                if RESERVED_REGION_NAMES.contains(&region_name) {
+                    return Err(TemplateError(
+                        region_range,
+                        template_path,
+                        template,
+                        error::TemplateErrorKind::ReservedRegionName,
+                        error_span,
+                    ));
+                }
+
+                if options.track_regions {
+                    let index = region_names.len();
+                    scopes.current().extend(quote::quote! {
+                        __remplate_region_starts[#index] = f.position();
+                    });
+                }
+
+                regions.push((region_range, region_names.len()));
+                region_names.push(region_name);
+            }
+            Ok(TemplateExpression::RegionEnd(region_range)) => match regions.pop() {
+                Some((_, index)) if options.track_regions => {
+                    scopes.current().extend(quote::quote! {
+                        __remplate_regions[#index] |= f.position() > __remplate_region_starts[#index];
+                    });
+                }
+                Some(_) => {}
+                None => {
+                    return Err(TemplateError(
+                        region_range,
+                        template_path,
+                        template,
+                        error::TemplateErrorKind::UnexpectedScopeEnd,
+                        error_span,
+                    ));
+                }
+            },
+            Ok(TemplateExpression::Translation(translation_range, key)) => {
+                let Some(messages) = options.messages else {
+                    return Err(TemplateError(
+                        translation_range,
+                        template_path,
+                        template,
+                        error::TemplateErrorKind::MissingCatalog,
+                        error_span,
+                    ));
+                };
+
+                match messages.get(&key) {
+                    Some(message) => {
+                        if scopes.is_root() {
+                            minimum_size += message.len();
+                        }
+                        maximum_size = maximum_size.map(|size| size + message.len());
+                        write_template_fragment(scopes.current(), message);
+                        static_text.push_str(message);
+                    }
+                    None => {
+                        return Err(TemplateError(
+                            translation_range,
+                            template_path,
+                            template,
+                            error::TemplateErrorKind::MissingTranslation,
+                            error_span,
+                        ))
+                    }
+                }
+            }
+            Ok(TemplateExpression::Extends(extends_range, _)) => {
+                return Err(TemplateError(
+                    extends_range,
+                    template_path,
+                    template,
+                    error::TemplateErrorKind::MisplacedExtends,
+                    error_span,
+                ));
+            }
+            Ok(TemplateExpression::Offset(offset_range)) if !cfg!(feature = "offset") => {
+                return Err(TemplateError(
+                    offset_range,
+                    template_path,
+                    template,
+                    error::TemplateErrorKind::OffsetFeature,
+                    error_span,
+                ));
+            }
+            Ok(expression) => {
+                uses_offset |= matches!(expression, TemplateExpression::Offset(_));
+                expression.to_tokens(&mut scopes, template_path, error_span)?
+            }
+            Err(()) => {}
+        }
+        if is_scope_opening {
+            scopes.bind(code_block);
+        }
+
+        template_fragment = template_fragment_ranges
+            .next()
+            .map(|template_fragment_range| &template[template_fragment_range.clone()])
+            .unwrap_or_default();
+        is_first_template_fragment = false;
+
+        if options.trim_blocks && is_block_without_output {
+            template_fragment = strip_leading_newline(template_fragment);
+        }
+    }
+
+    if options.region.is_none() {
+        let minified_fragment = options
+            .minify
+            .then(|| minify::minify_fragment(template_fragment, &mut preformatted_element));
+        let written_fragment = minified_fragment.as_deref().unwrap_or(template_fragment);
+        let fragment_size = written_fragment.len();
+        minimum_size += fragment_size;
+        maximum_size = maximum_size.map(|size| size + fragment_size);
+
+        write_template_fragment(scopes.current(), written_fragment);
+        static_text.push_str(written_fragment);
+    }
+
+    if let Some((escape_range, _)) = escapes.pop() {
+        return Err(TemplateError(
+            escape_range,
+            template_path,
+            template,
+            error::TemplateErrorKind::UnclosedScope,
+            error_span,
+        ));
+    }
+
+    if let Some((region_range, _)) = regions.pop() {
+        return Err(TemplateError(
+            region_range,
+            template_path,
+            template,
+            error::TemplateErrorKind::UnclosedScope,
+            error_span,
+        ));
+    }
+
+    let code = scopes.into_tokens().map_err(|opening_range| {
+        TemplateError(
+            opening_range,
+            template_path,
+            template,
+            error::TemplateErrorKind::UnclosedScope,
+            error_span,
+        )
+    })?;
+
+    Ok(RemplateData {
+        estimated_template_size,
+        remplate_code: code,
+        included_paths,
+        region_names: region_names.into_iter().map(String::from).collect(),
+        uses_offset,
+        size_hint: (minimum_size, maximum_size),
+        static_text,
+    })
+}
+
+struct TemplateBlock<'a> {
+    name: &'a str,
+    directive_range: Range<usize>,
+    range: Range<usize>,
+    is_nested: bool,
+}
+
+fn extended_path(
+    template: &str,
+    parse_result: &remplate_parser::ParseResult,
+) -> Option<(Range<usize>, String)> {
+    let first_block_range = parse_result.code_block_fragment_ranges.first()?;
+    if !template[..(first_block_range.start - 1)].trim().is_empty() {
+        return None;
+    }
+
+    match TemplateExpression::try_from((template, first_block_range.clone())) {
+        Ok(TemplateExpression::Extends(extends_range, base_path)) => {
+            Some((extends_range, base_path))
+        }
+        _ => None,
+    }
+}
+
+fn template_blocks<'a>(
+    template: &'a str,
+    parse_result: &remplate_parser::ParseResult,
+) -> Result<Vec<TemplateBlock<'a>>, (Range<usize>, error::TemplateErrorKind)> {
+    let mut blocks = Vec::new();
+    let mut open_blocks = Vec::new();
+
+    for block_range in &parse_result.code_block_fragment_ranges {
+        match TemplateExpression::try_from((template, block_range.clone())) {
+            Ok(TemplateExpression::BlockStart(directive_range, name)) => {
+                open_blocks.push((name, directive_range, block_range.start - 1));
+            }
+            Ok(TemplateExpression::BlockEnd(directive_range)) => {
+                let Some((name, opening_range, block_start)) = open_blocks.pop() else {
+                    return Err((
+                        directive_range,
+                        error::TemplateErrorKind::UnexpectedScopeEnd,
+                    ));
+                };
+
+                blocks.push(TemplateBlock {
+                    name,
+                    directive_range: opening_range,
+                    range: block_start..(block_range.end + 1),
+                    is_nested: !open_blocks.is_empty(),
+                });
+            }
+            _ => {}
+        }
+    }
+
+    if let Some((_, opening_range, _)) = open_blocks.pop() {
+        return Err((opening_range, error::TemplateErrorKind::UnclosedScope));
+    }
+
+    blocks.sort_by_key(|block| block.range.start);
+
+    Ok(blocks)
+}
+
+fn extend_template<'a>(
+    template: &'a str,
+    template_path: &'a Path,
+    parse_result: &remplate_parser::ParseResult,
+    (extends_range, base_path): (Range<usize>, &str),
+    options: TemplateOptions,
+    error_span: proc_macro2::Span,
+) -> Result<RemplateData, TemplateError<'a>> {
+    let template_error =
+        |range, error_kind| TemplateError(range, template_path, template, error_kind, error_span);
+    let aborted_data = |remplate_code, base_path| RemplateData {
+        estimated_template_size: 0,
+        remplate_code,
+        included_paths: vec![base_path],
+        region_names: Vec::new(),
+        uses_offset: false,
+        size_hint: (0, None),
+        static_text: String::new(),
+    };
+
+    let (base_path, base) = read_included_file(template_path, base_path).map_err(|_| {
+        template_error(
+            extends_range.clone(),
+            error::TemplateErrorKind::UnreadableInclude,
+        )
+    })?;
+
+    if options.include_depth >= MAXIMUM_INCLUDE_DEPTH {
+        return Err(template_error(
+            extends_range,
+            error::TemplateErrorKind::IncludeDepth,
+        ));
+    }
+
+    let blocks = template_blocks(template, parse_result)
+        .map_err(|(range, error_kind)| template_error(range, error_kind))?;
+    let base_blocks = parse_template_cached(&base, &base_path)
+        .map_err(|error| {
+            TemplateError::from((error, base_path.as_path(), base.as_str(), error_span))
+        })
+        .and_then(|base_parse_result| {
+            template_blocks(&base, &base_parse_result).map_err(|(range, error_kind)| {
+                TemplateError(range, &base_path, &base, error_kind, error_span)
+            })
+        });
+    let base_blocks = match base_blocks {
+        Ok(base_blocks) => base_blocks,
+        Err(error) => return Ok(aborted_data(error.abortion_error(), base_path.clone())),
+    };
+
+    let mut overriding_blocks: Vec<&TemplateBlock> = Vec::new();
+    for block in blocks.iter().filter(|block| !block.is_nested) {
+        if overriding_blocks
+            .iter()
+            .any(|overriding_block| overriding_block.name == block.name)
+        {
+            return Err(template_error(
+                block.directive_range.clone(),
+                error::TemplateErrorKind::DuplicateBlock,
+            ));
+        }
+
+        if !base_blocks
+            .iter()
+            .any(|base_block| base_block.name == block.name)
+        {
+            return Err(template_error(
+                block.directive_range.clone(),
+                error::TemplateErrorKind::UnknownBlock,
+            ));
+        }
+
+        overriding_blocks.push(block);
+    }
+
+    let mut extended_template = String::with_capacity(base.len());
+    let mut position = 0;
+    for base_block in &base_blocks {
+        if base_block.range.start < position {
+            continue;
+        }
+
+        if let Some(block) = overriding_blocks
+            .iter()
+            .find(|block| block.name == base_block.name)
+        {
+            extended_template.push_str(&base[position..base_block.range.start]);
+            extended_template.push_str(&template[block.range.clone()]);
+            position = base_block.range.end;
+        }
+    }
+    extended_template.push_str(&base[position..]);
+
+    let extended_options = TemplateOptions {
+        include_depth: options.include_depth + 1,
+        ..options
+    };
+
+    match create_code(&extended_template, &base_path, extended_options, error_span) {
+        Ok(mut remplate_data) => {
+            remplate_data.included_paths.push(base_path);
+            Ok(remplate_data)
+        }
+        Err(error) => Ok(aborted_data(error.abortion_error(), base_path.clone())),
+    }
+}
+
+fn parse_include(include: &str) -> Option<(String, proc_macro2::TokenStream)> {
+    use proc_macro2::TokenTree;
+
+    if !include.starts_with(char::is_whitespace) {
+        return None;
+    }
+
+    let mut tokens = proc_macro2::TokenStream::from_str(include)
+        .ok()?
+        .into_iter();
+
+    let include_path = match tokens.next()? {
+        TokenTree::Literal(literal) => match syn::Lit::new(literal) {
+            syn::Lit::Str(include_path) => include_path.value(),
+            _ => return None,
+        },
+        _ => return None,
+    };
+
+    let binding_group = match (tokens.next(), tokens.next(), tokens.next()) {
+        (None, None, None) => return Some((include_path, proc_macro2::TokenStream::new())),
+        (Some(TokenTree::Ident(with)), Some(TokenTree::Group(group)), None)
+            if with == "with" && group.delimiter() == proc_macro2::Delimiter::Parenthesis =>
+        {
+            group.stream()
+        }
+        _ => return None,
+    };
+
+    let mut bindings = proc_macro2::TokenStream::new();
+    let mut binding_tokens = binding_group.into_iter().peekable();
+
+    while binding_tokens.peek().is_some() {
+        let name = match binding_tokens.next()? {
+            TokenTree::Ident(name) => name,
+            _ => return None,
+        };
+
+        match binding_tokens.next()? {
+            TokenTree::Punct(punct) if punct.as_char() == '=' => {}
+            _ => return None,
+        }
+
+        let value: proc_macro2::TokenStream = iter::from_fn(|| {
+            binding_tokens.next_if(
+                |token| !matches!(token, TokenTree::Punct(punct) if punct.as_char() == ','),
+            )
+        })
+        .collect();
+        binding_tokens.next();
+
+        bindings.extend(quote::quote! { let #name = #value; });
+    }
+
+    Some((include_path, bindings))
+}
+
+fn read_included_file(
+    template_path: &Path,
+    include_path: &str,
+) -> Result<(PathBuf, String), std::io::Error> {
+    let mut included_path = template_path
+        .parent()
+        .map(Path::to_path_buf)
+        .unwrap_or_default();
+    included_path.push(include_path);
+
+    let included_path = std::fs::canonicalize(included_path)?;
+    let included_content = std::fs::read_to_string(&included_path)?;
+
+    Ok((included_path, included_content))
+}
+
+fn create_include_bytes(file_path: &Path) -> proc_macro2::TokenStream {
+    let file_path = file_path.to_string_lossy();
+
+    quote::quote! {
+        ::core::include_bytes!(#file_path);
+    }
+}
+
+#[derive(Debug)]
+enum PathCanonicalizationError {
+    CurrentDir(std::io::Error),
+    RootVariable {
+        variable: String,
+        source: std::env::VarError,
+    },
+    PathPointerVariable {
+        variable: String,
+        source: std::env::VarError,
+    },
+    PathPointerFile {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    IoError {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+}
+
+impl std::fmt::Display for PathCanonicalizationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            PathCanonicalizationError::CargoManifestDirVariable(error) => {
-                f.write_fmt(format_args!("CARGO_MANIFEST_DIR - {:?}", error))
+            PathCanonicalizationError::CurrentDir(error) => f.write_fmt(format_args!(
+                "CARGO_MANIFEST_DIR is not set and the current directory is unavailable - {}",
+                error
+            )),
+            PathCanonicalizationError::RootVariable { variable, source } => {
+                f.write_fmt(format_args!(
+                    "Unable to read the template root from the environment variable {} - {}",
+                    variable, source
+                ))
+            }
+            PathCanonicalizationError::PathPointerVariable { variable, source } => {
+                f.write_fmt(format_args!(
+                    "Unable to read the template path file from the environment variable {} - {}",
+                    variable, source
+                ))
+            }
+            PathCanonicalizationError::PathPointerFile { path, source } => {
+                f.write_fmt(format_args!(
+                    "Unable to read the template path file {:?} - {}",
+                    path, source
+                ))
             }
             PathCanonicalizationError::IoError { path, source } => {
                 f.write_fmt(format_args!("{} - {:?}", source, path))
@@ -254,76 +1911,697 @@ impl std::fmt::Display for PathCanonicalizationError {
     }
 }
 
-impl From<(PathBuf, std::io::Error)> for PathCanonicalizationError {
-    fn from(value: (PathBuf, std::io::Error)) -> Self {
-        Self::IoError {
-            path: value.0,
-            source: value.1,
+impl From<(PathBuf, std::io::Error)> for PathCanonicalizationError {
+    fn from(value: (PathBuf, std::io::Error)) -> Self {
+        Self::IoError {
+            path: value.0,
+            source: value.1,
+        }
+    }
+}
+
+fn base_directory(
+    manifest_dir: Option<std::ffi::OsString>,
+) -> Result<PathBuf, PathCanonicalizationError> {
+    match manifest_dir {
+        Some(manifest_dir) => Ok(PathBuf::from(manifest_dir)),
+        None => std::env::current_dir().map_err(PathCanonicalizationError::CurrentDir),
+    }
+}
+
+fn canonicalize_path<P>(
+    path: P,
+    root_variable: Option<&str>,
+) -> Result<PathBuf, PathCanonicalizationError>
+where
+    P: AsRef<Path>,
+{
+    let mut canonicalized_path = base_directory(std::env::var_os("CARGO_MANIFEST_DIR"))?;
+
+    if let Some(root_variable) = root_variable {
+        canonicalized_path.push(std::env::var(root_variable).map_err(|source| {
+            PathCanonicalizationError::RootVariable {
+                variable: root_variable.to_string(),
+                source,
+            }
+        })?);
+    }
+
+    canonicalized_path.push(path.as_ref());
+
+    std::fs::canonicalize(canonicalized_path.clone()).map_err(|e| (canonicalized_path, e).into())
+}
+
+fn read_path_pointer(variable: &str) -> Result<PathBuf, PathCanonicalizationError> {
+    let mut pointer_path = base_directory(std::env::var_os("CARGO_MANIFEST_DIR"))?;
+    pointer_path.push(std::env::var(variable).map_err(|source| {
+        PathCanonicalizationError::PathPointerVariable {
+            variable: variable.to_string(),
+            source,
+        }
+    })?);
+
+    let template_path = std::fs::read_to_string(&pointer_path).map_err(|source| {
+        PathCanonicalizationError::PathPointerFile {
+            path: pointer_path.clone(),
+            source,
+        }
+    })?;
+
+    let mut resolved_path = pointer_path
+        .parent()
+        .map(Path::to_path_buf)
+        .unwrap_or_default();
+    resolved_path.push(template_path.trim());
+
+    Ok(resolved_path)
+}
+
+fn precompressed_template(
+    template: &str,
+    static_text: &str,
+    RemplatePrecompress(encoding, span): RemplatePrecompress,
+) -> Result<Vec<u8>, syn::Error> {
+    let has_code_blocks = remplate_parser::parse_fragments(template)
+        .map(|fragments| {
+            fragments
+                .iter()
+                .any(|fragment| matches!(fragment, remplate_parser::Fragment::Dynamic(_)))
+        })
+        .unwrap_or(true);
+
+    if has_code_blocks {
+        return Err(syn::Error::new(
+            span,
+            "Only templates without code blocks can be precompressed",
+        ));
+    }
+
+    compress(static_text.as_bytes(), &encoding).map_err(|message| syn::Error::new(span, message))
+}
+
+#[cfg(feature = "compression")]
+fn compress(content: &[u8], encoding: &str) -> Result<Vec<u8>, String> {
+    use std::io::Write;
+
+    let compression = flate2::Compression::best();
+    let result = match encoding {
+        "gzip" => {
+            let mut encoder = flate2::write::GzEncoder::new(Vec::new(), compression);
+            encoder.write_all(content).and_then(|_| encoder.finish())
+        }
+        "deflate" => {
+            let mut encoder = flate2::write::DeflateEncoder::new(Vec::new(), compression);
+            encoder.write_all(content).and_then(|_| encoder.finish())
+        }
+        _ => {
+            return Err(format!(
+                "Unknown precompression encoding `{}`, expected `gzip` or `deflate`",
+                encoding
+            ))
+        }
+    };
+
+    result.map_err(|error| format!("Unable to compress the template - {}", error))
+}
+
+#[cfg(not(feature = "compression"))]
+fn compress(_content: &[u8], _encoding: &str) -> Result<Vec<u8>, String> {
+    Err(String::from(
+        "Precompressing templates requires the `compression` feature",
+    ))
+}
+
+struct RemplateData {
+    estimated_template_size: usize,
+    remplate_code: proc_macro2::TokenStream,
+    included_paths: Vec<PathBuf>,
+    region_names: Vec<String>,
+    uses_offset: bool,
+    size_hint: (usize, Option<usize>),
+    static_text: String,
+}
+
+fn offset_tracking_code(
+    code: proc_macro2::TokenStream,
+    uses_offset: bool,
+) -> proc_macro2::TokenStream {
+    match uses_offset {
+        true => quote::quote! {
+            let f = &mut ::remplate::OffsetWriter::new(f);
+            #code
+        },
+        false => code,
+    }
+}
+
+fn display_fmt(
+    code: proc_macro2::TokenStream,
+    inline_display: bool,
+    error_span: proc_macro2::Span,
+) -> proc_macro2::TokenStream {
+    let inline_part = inline_display.then(|| quote::quote! { #[inline] });
+
+    quote::quote_spanned! { error_span =>
+        #inline_part
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            #code
+            ::core::result::Result::Ok(())
+        }
+    }
+}
+
+fn html_checked_code(code: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    quote::quote! {
+        #[cfg(debug_assertions)]
+        {
+            let template = ::remplate::InlineTemplate(
+                |f: &mut ::core::fmt::Formatter<'_>| -> ::core::fmt::Result {
+                    #code
+                    ::core::result::Result::Ok(())
+                }
+            );
+
+            let mut rendered = ::std::string::String::new();
+            ::core::fmt::Write::write_fmt(&mut rendered, format_args!("{}", template))?;
+            ::remplate::html_check::assert_balanced_tags(&rendered);
+
+            ::core::fmt::Write::write_str(f, &rendered)?;
+        }
+        #[cfg(not(debug_assertions))]
+        {
+            #code
+        }
+    }
+}
+
+fn each_element_code(code: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    match code.is_empty() {
+        true => code,
+        false => quote::quote! {
+            for item in &self.0 {
+                #code
+            }
+        },
+    }
+}
+
+fn handle_template<'a>(
+    template: &'a str,
+    template_path: &'a Path,
+    options: TemplateOptions,
+    error_span: proc_macro2::Span,
+) -> Result<RemplateData, TemplateError<'a>> {
+    create_code(template, template_path, options, error_span)
+}
+
+fn read_template(template_path: &Path, error_span: proc_macro2::Span) -> syn::Result<String> {
+    std::fs::read_to_string(template_path).map_err(|error| {
+        let message = format!("Unable to read template at {:?} - {}", template_path, error);
+        syn::Error::new(error_span, message)
+    })
+}
+
+fn rendered_code(
+    code: proc_macro2::TokenStream,
+    is_each: bool,
+    uses_offset: bool,
+    error_span: proc_macro2::Span,
+) -> proc_macro2::TokenStream {
+    let code = match is_each {
+        true => each_element_code(code),
+        false => code,
+    };
+    let code = offset_tracking_code(code, uses_offset);
+
+    match code.is_empty() {
+        true => quote::quote! { let _ = f; },
+        false => span_manipulation::set_span_for_token_stream(code, error_span),
+    }
+}
+
+fn resolve_template_path(
+    template_path: RemplateTemplatePath,
+    template_root: Option<&RemplateRoot>,
+    error_span: proc_macro2::Span,
+) -> syn::Result<PathBuf> {
+    let template_path = match template_path {
+        RemplateTemplatePath::Path(RemplatePath(path, _))
+        | RemplateTemplatePath::Each(RemplateEach(path, _)) => PathBuf::from(path),
+        RemplateTemplatePath::PathFrom(RemplatePathFrom(variable, _)) => {
+            read_path_pointer(&variable)
+                .map_err(|error| syn::Error::new(error_span, error.to_string()))?
+        }
+    };
+
+    canonicalize_path(
+        template_path,
+        template_root.map(|RemplateRoot(root, _)| root.as_str()),
+    )
+    .map_err(|error| {
+        let span = match (&error, template_root) {
+            (PathCanonicalizationError::RootVariable { .. }, Some(RemplateRoot(_, root_span))) => {
+                *root_span
+            }
+            _ => error_span,
+        };
+
+        syn::Error::new(span, error.to_string())
+    })
+}
+
+fn load_catalog(
+    locale: Option<RemplateLocale>,
+    catalog: Option<RemplateCatalog>,
+    template_root: Option<&str>,
+) -> syn::Result<Option<(PathBuf, HashMap<String, String>)>> {
+    let (Some(RemplateLocale(locale, locale_span)), Some(RemplateCatalog(catalog, catalog_span))) =
+        (locale, catalog)
+    else {
+        return Ok(None);
+    };
+
+    let catalog_path = canonicalize_path(catalog, template_root)
+        .map_err(|error| syn::Error::new(catalog_span, error.to_string()))?;
+
+    let catalog_content = std::fs::read_to_string(&catalog_path).map_err(|error| {
+        let message = format!("Unable to read catalog at {:?} - {}", catalog_path, error);
+        syn::Error::new(catalog_span, message)
+    })?;
+
+    match catalog_parsing::parse_catalog(&catalog_content, &locale) {
+        Ok(messages) => Ok(Some((catalog_path, messages))),
+        Err(error) => {
+            let span = match error {
+                catalog_parsing::CatalogParseError::MissingLocale => locale_span,
+                catalog_parsing::CatalogParseError::InvalidLine(_) => catalog_span,
+            };
+
+            Err(syn::Error::new(
+                span,
+                format!("{} in {:?}", error, catalog_path),
+            ))
+        }
+    }
+}
+
+#[proc_macro_derive(Remplate, attributes(remplate, remplate_context))]
+pub fn derive_remplate(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    match expand_remplate(item) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.into_compile_error().into(),
+    }
+}
+
+fn expand_remplate(item: proc_macro::TokenStream) -> syn::Result<proc_macro2::TokenStream> {
+    let MacroParseResult {
+        impl_generics,
+        type_generics,
+        where_clause,
+        type_ident,
+        context_field,
+        attributes:
+            RemplateAttributes {
+                template_path,
+                template_root,
+                estimated_size_expr,
+                context,
+                trim_blocks,
+                lstrip_blocks,
+                bound: _,
+                precompress,
+                runtime_escape,
+                lint,
+                check_html,
+                inline_display,
+                minify,
+                contextual_escape,
+                escape,
+                static_only,
+                uses,
+                postprocess,
+                method,
+                glob,
+                locale,
+                catalog,
+            },
+    } = macro_parsing::parse_derive_macro_input(item)?;
+
+    let error_span = template_path.span();
+    let is_each = matches!(template_path, RemplateTemplatePath::Each(_));
+
+    let canonicalized_path =
+        resolve_template_path(template_path, template_root.as_ref(), error_span)?;
+    let template_root = template_root
+        .as_ref()
+        .map(|RemplateRoot(root, _)| root.as_str());
+    let template = read_template(&canonicalized_path, error_span)?;
+
+    if static_only {
+        check_static_only(&template, &canonicalized_path, error_span)?;
+    }
+
+    let catalog = load_catalog(locale, catalog, template_root)?;
+
+    let template_options = TemplateOptions {
+        trim_blocks,
+        lstrip_blocks,
+        has_context_field: context_field.is_some(),
+        runtime_escape: false,
+        lint,
+        record_spans: false,
+        annotate_expressions: false,
+        region: None,
+        track_regions: false,
+        minify,
+        contextual_escape,
+        escape,
+        escape_by_extension: true,
+        messages: catalog.as_ref().map(|(_, messages)| messages),
+        include_depth: 0,
+    };
+
+    let RemplateData {
+        estimated_template_size,
+        remplate_code,
+        included_paths,
+        region_names,
+        uses_offset,
+        size_hint,
+        static_text,
+    } = handle_template(&template, &canonicalized_path, template_options, error_span)?;
+
+    let variant_code = |options| -> syn::Result<proc_macro2::TokenStream> {
+        let options = TemplateOptions {
+            lint: false,
+            ..options
+        };
+        let RemplateData {
+            remplate_code,
+            uses_offset,
+            ..
+        } = handle_template(&template, &canonicalized_path, options, error_span)?;
+
+        Ok(rendered_code(
+            remplate_code,
+            is_each,
+            uses_offset,
+            error_span,
+        ))
+    };
+
+    let runtime_escaped_code = runtime_escape
+        .then(|| {
+            variant_code(TemplateOptions {
+                runtime_escape: true,
+                ..template_options
+            })
+        })
+        .transpose()?;
+
+    let span_recording_code = (cfg!(feature = "hydration") && postprocess.is_none())
+        .then(|| {
+            variant_code(TemplateOptions {
+                record_spans: true,
+                ..template_options
+            })
+        })
+        .transpose()?;
+
+    let annotated_code = cfg!(feature = "debug-render")
+        .then(|| {
+            variant_code(TemplateOptions {
+                annotate_expressions: true,
+                ..template_options
+            })
+        })
+        .transpose()?;
+
+    let region_codes = (0..region_names.len())
+        .map(|index| {
+            variant_code(TemplateOptions {
+                region: Some(index),
+                ..template_options
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let region_info_code = (!region_names.is_empty())
+        .then(|| {
+            variant_code(TemplateOptions {
+                track_regions: true,
+                ..template_options
+            })
+        })
+        .transpose()?;
+
+    let include_bytes_part: proc_macro2::TokenStream = iter::once(&canonicalized_path)
+        .chain(&included_paths)
+        .chain(catalog.as_ref().map(|(catalog_path, _)| catalog_path))
+        .map(|path| create_include_bytes(path))
+        .collect();
+
+    let remplate_code = rendered_code(remplate_code, is_each, uses_offset, error_span);
+
+    let estimated_template_size = estimated_template_size.max(MINIMUM_ESTIMATED_SIZE);
+
+    let use_part: proc_macro2::TokenStream = uses
+        .iter()
+        .map(|RemplateUse(use_path)| quote::quote! { use #use_path; })
+        .collect();
+
+    let context_field_part = context_field.map(|context_field| {
+        quote::quote_spanned! { error_span =>
+            let __remplate_context = &self.#context_field;
+            let _ = __remplate_context;
+        }
+    });
+
+    let template_impl = method_generation::TemplateImpl {
+        impl_generics,
+        type_generics,
+        where_clause,
+        type_ident,
+        use_part,
+        context_field_part,
+        is_each,
+        span: error_span,
+    };
+
+    if let Some(RemplateContext(context_type, context_span)) = context {
+        if !region_names.is_empty() {
+            return Err(syn::Error::new(
+                context_span,
+                "The `context` option can not be combined with regions",
+            ));
         }
+
+        let method_generation::TemplateImpl { use_part, .. } = &template_impl;
+        let display_fmt = display_fmt(
+            quote::quote! {
+                let ctx = self.0;
+                let _ = ctx;
+                #include_bytes_part
+                #use_part
+                #remplate_code
+            },
+            inline_display,
+            error_span,
+        );
+
+        let method = match method {
+            Some(RemplateMethod(method, _)) => method,
+            None => proc_macro2::Ident::new("render_ctx", error_span),
+        };
+
+        return Ok(template_impl.context_methods(
+            &context_type,
+            &method,
+            display_fmt,
+            estimated_template_size,
+            span_recording_code,
+            annotated_code,
+        ));
     }
-}
 
-fn canonicalize_path<P>(path: P) -> Result<PathBuf, PathCanonicalizationError>
-where
-    P: AsRef<Path>,
-{
-    let mut canonicalized_path = PathBuf::from(
-        std::env::var("CARGO_MANIFEST_DIR")
-            .map_err(PathCanonicalizationError::CargoManifestDirVariable)?,
-    );
-    canonicalized_path.push(path.as_ref());
+    let size_hint_part = {
+        let (minimum_size, maximum_size) = match is_each {
+            true => (0, None),
+            false => size_hint,
+        };
+        let maximum_size = match maximum_size {
+            Some(maximum_size) => quote::quote! { ::core::option::Option::Some(#maximum_size) },
+            None => quote::quote! { ::core::option::Option::None },
+        };
 
-    std::fs::canonicalize(canonicalized_path.clone()).map_err(|e| (canonicalized_path, e).into())
-}
+        quote::quote! {
+            const SIZE_HINT: (usize, ::core::option::Option<usize>) = (#minimum_size, #maximum_size);
+        }
+    };
 
-struct RemplateData {
-    estimated_template_size: usize,
-    remplate_code: proc_macro2::TokenStream,
-}
+    let estimated_size_part = match (estimated_size_expr, is_each) {
+        (Some(RemplateEstimatedSizeExpr(expression)), _) => Some(quote::quote! {
+            fn estimated_size(&self) -> usize {
+                #expression
+            }
+        }),
+        (None, true) => Some(quote::quote! {
+            fn estimated_size(&self) -> usize {
+                Self::ESTIMATED_SIZE * self.0.len().max(1)
+            }
+        }),
+        (None, false) => None,
+    };
 
-fn handle_template(
-    template: &str,
-    error_span: proc_macro2::Span,
-) -> Result<RemplateData, TemplateError<'_>> {
-    let (estimated_template_size, code) = create_code(template, error_span)?;
+    let postprocess_part = postprocess.map(|RemplatePostprocess(postprocess)| {
+        quote::quote! {
+            const HAS_POSTPROCESS: bool = true;
 
-    Ok(RemplateData {
-        estimated_template_size,
-        remplate_code: code,
-    })
-}
+            fn postprocess(rendered: ::std::string::String) -> ::std::string::String {
+                #postprocess(rendered)
+            }
+        }
+    });
 
-static TEMPLATE_PATH: OnceLock<PathBuf> = OnceLock::new();
-static INVALID_STATE_MESSAGE: &str = "Internal state should be set";
-static STATE_ALREADY_SET_MESSAGE: &str = "Internal state should be set only once";
+    let precompressed_part = precompress
+        .map(|precompress| precompressed_template(&template, &static_text, precompress))
+        .transpose()?
+        .map(|compressed_template| template_impl.precompressed_method(&compressed_template));
+    let runtime_escape_part =
+        runtime_escaped_code.map(|code| template_impl.runtime_escape_method(code));
+    let span_recording_part =
+        span_recording_code.map(|code| template_impl.span_recording_method(code));
+    let debug_render_part = annotated_code.map(|code| template_impl.debug_render_method(code));
+    let region_part: proc_macro2::TokenStream = region_names
+        .iter()
+        .zip(region_codes)
+        .map(|(region_name, region_code)| template_impl.region_method(region_name, region_code))
+        .collect();
+    let region_info_part =
+        region_info_code.map(|code| template_impl.region_info_method(&region_names, code));
+    let glob_part = glob
+        .map(|glob| {
+            template_impl.glob_method(
+                &glob,
+                template_root,
+                method.as_ref().map(|RemplateMethod(method, _)| method),
+                template_options,
+                &region_names,
+            )
+        })
+        .transpose()?;
 
-#[proc_macro_derive(Remplate, attributes(remplate))]
-pub fn derive_remplate(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    let MacroParseResult {
+    let method_generation::TemplateImpl {
         impl_generics,
         type_generics,
         where_clause,
         type_ident,
-        template_path: RemplatePath(template_path, error_span),
-    } = match macro_parsing::parse_derive_macro_input(item) {
-        Ok(template_path) => template_path,
-        Err(error) => return error.to_compile_error().into(),
+        use_part,
+        context_field_part,
+        ..
+    } = &template_impl;
+
+    let display_code = quote::quote! {
+        #use_part
+        #context_field_part
+        #remplate_code
+    };
+    let display_code = match check_html {
+        true => html_checked_code(display_code),
+        false => display_code,
     };
+    let display_fmt = display_fmt(
+        quote::quote! {
+            #include_bytes_part
+            #display_code
+        },
+        inline_display,
+        error_span,
+    );
 
-    let canonicalized_path = match canonicalize_path(template_path) {
-        Ok(path) => {
-            TEMPLATE_PATH.set(path).expect(STATE_ALREADY_SET_MESSAGE);
-            TEMPLATE_PATH.get().expect(INVALID_STATE_MESSAGE)
+    Ok(quote::quote_spanned! { error_span =>
+        impl #impl_generics ::core::fmt::Display for #type_ident #type_generics #where_clause {
+            #display_fmt
         }
+        impl #impl_generics ::remplate::Remplate for #type_ident #type_generics #where_clause {
+            const ESTIMATED_SIZE: usize = #estimated_template_size;
+            #size_hint_part
+            #estimated_size_part
+            #postprocess_part
+        }
+        #precompressed_part
+        #runtime_escape_part
+        #span_recording_part
+        #debug_render_part
+        #region_part
+        #region_info_part
+        #glob_part
+    })
+}
+
+#[proc_macro_derive(Template, attributes(remplate, remplate_context))]
+pub fn derive_template(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    derive_remplate(item)
+}
+
+#[proc_macro]
+pub fn remplate_inline(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let template_literal = match syn::parse::<syn::LitStr>(item) {
+        Ok(template_literal) => template_literal,
+        Err(error) => return error.to_compile_error().into(),
+    };
+    let template = template_literal.value();
+    let error_span = template_literal.span();
+
+    let RemplateData {
+        remplate_code,
+        uses_offset,
+        ..
+    } = match create_code(
+        &template,
+        Path::new("inline template"),
+        TemplateOptions::default(),
+        error_span,
+    ) {
+        Ok(remplate_data) => remplate_data,
+        Err(error) => return error.abortion_error().into(),
+    };
+
+    let remplate_code = offset_tracking_code(remplate_code, uses_offset);
+    let remplate_code = span_manipulation::set_span_for_token_stream(remplate_code, error_span);
+
+    quote::quote_spanned! { error_span =>
+        ::remplate::InlineTemplate(
+            |f: &mut ::core::fmt::Formatter<'_>| -> ::core::fmt::Result {
+                #remplate_code
+                ::core::result::Result::Ok(())
+            }
+        )
+    }
+    .into()
+}
+
+#[proc_macro]
+pub fn render_with(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let RenderWithInput {
+        template_path,
+        bindings,
+    } = match syn::parse::<RenderWithInput>(item) {
+        Ok(render_with_input) => render_with_input,
+        Err(error) => return error.to_compile_error().into(),
+    };
+    let error_span = template_path.span();
+
+    let canonicalized_path = match canonicalize_path(template_path.value(), None) {
+        Ok(path) => path,
         Err(error) => {
-            let message = format!("{}", error);
-            return syn::Error::new(error_span, message)
+            return syn::Error::new(error_span, error.to_string())
                 .into_compile_error()
-                .into();
+                .into()
         }
     };
 
-    let template = match std::fs::read_to_string(canonicalized_path) {
+    let template = match std::fs::read_to_string(&canonicalized_path) {
         Ok(content) => content,
         Err(error) => {
             let message = format!(
@@ -336,29 +2614,572 @@ pub fn derive_remplate(item: proc_macro::TokenStream) -> proc_macro::TokenStream
         }
     };
 
+    let template_options = TemplateOptions {
+        escape_by_extension: true,
+        ..TemplateOptions::default()
+    };
+
     let RemplateData {
         estimated_template_size,
         remplate_code,
-    } = match handle_template(&template, error_span) {
+        included_paths,
+        uses_offset,
+        ..
+    } = match create_code(&template, &canonicalized_path, template_options, error_span) {
         Ok(remplate_data) => remplate_data,
         Err(error) => return error.abortion_error().into(),
     };
+    let remplate_code = offset_tracking_code(remplate_code, uses_offset);
+
+    let remplate_code = match remplate_code.is_empty() {
+        true => quote::quote! { let _ = f; },
+        false => span_manipulation::set_span_for_token_stream(remplate_code, error_span),
+    };
 
-    let include_bytes_part = create_include_bytes(canonicalized_path);
+    let include_bytes_part: proc_macro2::TokenStream = iter::once(&canonicalized_path)
+        .chain(&included_paths)
+        .map(|path| create_include_bytes(path))
+        .collect();
 
-    let remplate_code = span_manipulation::set_span_for_token_stream(remplate_code, error_span);
+    let bindings_part: proc_macro2::TokenStream = bindings
+        .iter()
+        .map(|(name, value)| quote::quote! { let #name = #value; })
+        .collect();
+
+    let estimated_template_size = estimated_template_size.max(MINIMUM_ESTIMATED_SIZE);
 
     quote::quote_spanned! { error_span =>
-        impl #impl_generics ::core::fmt::Display for #type_ident #type_generics #where_clause {
+        {
+            #include_bytes_part
+            #bindings_part
+            let template = ::remplate::InlineTemplate(
+                |f: &mut ::core::fmt::Formatter<'_>| -> ::core::fmt::Result {
+                    #remplate_code
+                    ::core::result::Result::Ok(())
+                }
+            );
+
+            let mut rendered = ::std::string::String::with_capacity(#estimated_template_size);
+            ::core::fmt::Write::write_fmt(&mut rendered, format_args!("{}", template))
+                .map(|()| rendered)
+        }
+    }
+    .into()
+}
+
+#[cfg(test)]
+mod create_code_tests {
+    use std::{collections::HashMap, path::Path, sync::Arc};
+
+    use crate::error::TemplateErrorKind;
+
+    use super::{create_code, estimate_size, parse_template_cached, TemplateOptions};
+
+    #[test]
+    fn create_code_without_empty_writes() {
+        let code = create_code(
+            "{ self.title }{ self.subtitle }",
+            Path::new("leading_interpolation.html"),
+            TemplateOptions::default(),
+            proc_macro2::Span::call_site(),
+        )
+        .map(|remplate_data| remplate_data.remplate_code)
+        .unwrap_or_else(|error| panic!("Failed to create code - {:?}", error.3));
+
+        assert!(!code.to_string().contains("write_str"));
+    }
+
+    #[test]
+    fn create_code_for_format_spec_without_expression() {
+        let code = create_code(
+            "<p>{ :? }</p>",
+            Path::new("missing_value.html"),
+            TemplateOptions::default(),
+            proc_macro2::Span::call_site(),
+        )
+        .map(|remplate_data| remplate_data.remplate_code)
+        .unwrap_or_else(|error| panic!("Failed to create code - {:?}", error.3));
+
+        let code = code.to_string();
+
+        assert!(code.contains("compile_error"));
+        assert!(code.contains("The format expression misses a value"));
+    }
+
+    #[test]
+    fn create_code_keeps_indentation_before_interpolation() {
+        let code = create_code(
+            "<ul>\n    { item }\n    { let x = 1; }\n</ul>",
+            Path::new("lstrip_blocks.html"),
+            TemplateOptions {
+                lstrip_blocks: true,
+                ..TemplateOptions::default()
+            },
+            proc_macro2::Span::call_site(),
+        )
+        .map(|remplate_data| remplate_data.remplate_code)
+        .unwrap_or_else(|error| panic!("Failed to create code - {:?}", error.3));
+
+        let code = code.to_string();
+
+        assert!(code.contains(r#""<ul>\n    ""#));
+        assert!(code.contains(r#""\n""#));
+        assert!(!code.contains(r#""\n    ""#));
+    }
+
+    #[test]
+    fn create_code_for_missing_translation() {
+        let messages = HashMap::from([(String::from("greeting"), String::from("Hello"))]);
+
+        let error = match create_code(
+            "{ t!(\"greeting\") }{ t!(\"farewell\") }",
+            Path::new("missing_translation.html"),
+            TemplateOptions {
+                messages: Some(&messages),
+                ..TemplateOptions::default()
+            },
+            proc_macro2::Span::call_site(),
+        ) {
+            Ok(_) => panic!("Created code for a missing translation"),
+            Err(error) => error,
+        };
+
+        assert!(matches!(error.3, TemplateErrorKind::MissingTranslation));
+        assert_eq!(error.0, 20..34);
+    }
+
+    #[test]
+    fn create_code_for_translation_without_catalog() {
+        let error = match create_code(
+            "<h1>{ t!(\"greeting\") }</h1>",
+            Path::new("translation_without_catalog.html"),
+            TemplateOptions::default(),
+            proc_macro2::Span::call_site(),
+        ) {
+            Ok(_) => panic!("Created code for a translation without a catalog"),
+            Err(error) => error,
+        };
+
+        assert!(matches!(error.3, TemplateErrorKind::MissingCatalog));
+    }
+
+    #[test]
+    fn create_code_for_unknown_escape() {
+        let error = match create_code(
+            "{% escape yaml %}{ self.x }{% endescape %}",
+            Path::new("unknown_escape.html"),
+            TemplateOptions::default(),
+            proc_macro2::Span::call_site(),
+        ) {
+            Ok(_) => panic!("Created code for an unknown escape context"),
+            Err(error) => error,
+        };
+
+        assert!(matches!(error.3, TemplateErrorKind::UnknownEscape));
+    }
+
+    #[test]
+    fn create_code_for_unclosed_escape() {
+        let error = match create_code(
+            "{% escape js %}{ self.x }",
+            Path::new("unclosed_escape.html"),
+            TemplateOptions::default(),
+            proc_macro2::Span::call_site(),
+        ) {
+            Ok(_) => panic!("Created code for an unclosed escape context"),
+            Err(error) => error,
+        };
+
+        assert!(matches!(error.3, TemplateErrorKind::UnclosedScope));
+    }
+
+    #[test]
+    fn create_code_for_unclosed_region() {
+        let error = match create_code(
+            "{% region header %}{ self.x }",
+            Path::new("unclosed_region.html"),
+            TemplateOptions::default(),
+            proc_macro2::Span::call_site(),
+        ) {
+            Ok(_) => panic!("Created code for an unclosed region"),
+            Err(error) => error,
+        };
+
+        assert!(matches!(error.3, TemplateErrorKind::UnclosedScope));
+    }
+
+    #[test]
+    fn create_code_for_duplicate_region() {
+        let error = match create_code(
+            "{% region a %}{% endregion %}{% region a %}{% endregion %}",
+            Path::new("duplicate_region.html"),
+            TemplateOptions::default(),
+            proc_macro2::Span::call_site(),
+        ) {
+            Ok(_) => panic!("Created code for a duplicate region"),
+            Err(error) => error,
+        };
+
+        assert!(matches!(error.3, TemplateErrorKind::DuplicateRegion));
+        assert_eq!(error.0, 30..42);
+    }
+
+    #[test]
+    fn create_code_for_reserved_region_name() {
+        let error = match create_code(
+            "{% region debug %}{% endregion %}",
+            Path::new("reserved_region.html"),
+            TemplateOptions::default(),
+            proc_macro2::Span::call_site(),
+        ) {
+            Ok(_) => panic!("Created code for a region named like a render method"),
+            Err(error) => error,
+        };
+
+        assert!(matches!(error.3, TemplateErrorKind::ReservedRegionName));
+        assert_eq!(error.0, 1..17);
+    }
+
+    #[test]
+    #[cfg(not(feature = "offset"))]
+    fn create_code_for_offset_without_feature() {
+        let error = match create_code(
+            "<p>{% offset %}</p>",
+            Path::new("offset.html"),
+            TemplateOptions::default(),
+            proc_macro2::Span::call_site(),
+        ) {
+            Ok(_) => panic!("Created code for an offset without the `offset` feature"),
+            Err(error) => error,
+        };
+
+        assert!(matches!(error.3, TemplateErrorKind::OffsetFeature));
+        assert_eq!(error.0, 4..14);
+    }
+
+    fn estimated_size_of(template: &str) -> usize {
+        let parse_result = remplate_parser::parse_template(template)
+            .unwrap_or_else(|error| panic!("Failed to parse template - {:?}", error));
+
+        estimate_size(template, &parse_result)
+    }
+
+    #[test]
+    fn estimate_size_of_static_template() {
+        assert_eq!(estimated_size_of("<p>static</p>"), 13);
+    }
+
+    #[test]
+    fn estimate_size_of_interpolation() {
+        assert_eq!(estimated_size_of("<p>{ self.x }</p>{# comment #}"), 23);
+    }
+
+    #[test]
+    fn estimate_size_of_loop() {
+        assert_eq!(
+            estimated_size_of("<ul>{ for item in &self.items { }<li>{ item }</li>{ } }</ul>"),
+            9 + 4 + 5 + 3 * 16
+        );
+    }
+
+    #[test]
+    fn parse_template_cached_per_content() {
+        let template_path = Path::new("cached.html");
+
+        let first = parse_template_cached("<p>{ self.a }</p>", template_path).unwrap();
+        let cached = parse_template_cached("<p>{ self.a }</p>", template_path).unwrap();
+        let changed = parse_template_cached("{ self.a }<p></p>", template_path).unwrap();
+
+        assert!(Arc::ptr_eq(&first, &cached));
+        assert!(!Arc::ptr_eq(&first, &changed));
+        assert_eq!(
+            *changed,
+            remplate_parser::parse_template("{ self.a }<p></p>").unwrap()
+        );
+    }
+
+    #[test]
+    fn create_code_with_literal_lint() {
+        let create_linted_code = |template| {
+            create_code(
+                template,
+                Path::new("lint.html"),
+                TemplateOptions {
+                    lint: true,
+                    ..TemplateOptions::default()
+                },
+                proc_macro2::Span::call_site(),
+            )
+            .map(|remplate_data| remplate_data.remplate_code.to_string())
+            .unwrap_or_else(|error| panic!("Failed to create code - {:?}", error.3))
+        };
+
+        assert!(create_linted_code(r#"<p>{ "x" }</p>"#).contains("deprecated"));
+        assert!(!create_linted_code("<p>{ self.x }</p>").contains("deprecated"));
+    }
+
+    #[test]
+    fn create_code_for_separator_outside_loop() {
+        let error = match create_code(
+            "{ self.x }{% sep %}, ",
+            Path::new("separator_outside_loop.html"),
+            TemplateOptions::default(),
+            proc_macro2::Span::call_site(),
+        ) {
+            Ok(_) => panic!("Created code for a separator outside of a loop"),
+            Err(error) => error,
+        };
+
+        assert!(matches!(error.3, TemplateErrorKind::UnexpectedSeparator));
+    }
+
+    #[test]
+    fn create_code_for_unbalanced_paren() {
+        let error = match create_code(
+            "<p>{ foo( }</p>",
+            Path::new("unbalanced.html"),
+            TemplateOptions::default(),
+            proc_macro2::Span::call_site(),
+        ) {
+            Ok(_) => panic!("Created code for an unbalanced expression"),
+            Err(error) => error,
+        };
+
+        assert_eq!(error.0, 8..9);
+
+        let message = error.abortion_error().to_string();
+
+        assert!(message.contains("Failed to find the matching delimiter for `(`"));
+    }
+}
+
+#[cfg(test)]
+mod static_only_tests {
+    use std::path::Path;
+
+    use crate::error::TemplateErrorKind;
+
+    use super::check_static_only;
+
+    #[test]
+    fn check_static_template() {
+        let result = check_static_only(
+            "{# license #}<p>MIT {{}c}</p>{% comment %}{ x }{% endcomment %}",
+            Path::new("static.html"),
+            proc_macro2::Span::call_site(),
+        );
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn check_template_with_interpolation() {
+        let error = match check_static_only(
+            "<p>{# note #}{ self.x }</p>",
+            Path::new("dynamic.html"),
+            proc_macro2::Span::call_site(),
+        ) {
+            Ok(()) => panic!("Accepted a template with an interpolation"),
+            Err(error) => error,
+        };
+
+        assert!(matches!(error.3, TemplateErrorKind::DynamicContent));
+        assert_eq!(error.0, 13..23);
+    }
+}
+
+#[cfg(test)]
+mod display_fmt_tests {
+    use super::display_fmt;
+
+    fn fmt_attributes(inline_display: bool) -> Vec<String> {
+        let display_fmt = display_fmt(
+            quote::quote! { f.write_str("text")?; },
+            inline_display,
+            proc_macro2::Span::call_site(),
+        );
+
+        syn::parse2::<syn::ImplItemFn>(display_fmt)
+            .expect("Generated fmt should be a valid method")
+            .attrs
+            .iter()
+            .map(|attribute| quote::ToTokens::to_token_stream(attribute).to_string())
+            .collect()
+    }
+
+    #[test]
+    fn inline_display_fmt() {
+        assert_eq!(fmt_attributes(true), vec!["# [inline]"]);
+    }
+
+    #[test]
+    fn display_fmt_without_inline() {
+        assert!(fmt_attributes(false).is_empty());
+    }
+}
+
+#[cfg(test)]
+mod whitespace_control_tests {
+    use super::strip_trailing_indentation;
+
+    #[test]
+    fn strip_mixed_indentation() {
+        assert_eq!(strip_trailing_indentation("<ul>\n\t  \t", false), "<ul>\n");
+        assert_eq!(strip_trailing_indentation(" \t", true), "");
+        assert_eq!(strip_trailing_indentation(" \t", false), " \t");
+    }
+
+    #[test]
+    fn keep_non_indentation_whitespace() {
+        assert_eq!(
+            strip_trailing_indentation("<p>\n\u{a0}\t", false),
+            "<p>\n\u{a0}\t"
+        );
+        assert_eq!(strip_trailing_indentation("<p>\n\x0c", false), "<p>\n\x0c");
+    }
+}
+
+#[cfg(test)]
+mod canonicalize_path_tests {
+    use super::{base_directory, canonicalize_path, read_path_pointer, PathCanonicalizationError};
+
+    #[test]
+    fn base_directory_without_manifest_dir() {
+        assert_eq!(
+            base_directory(None).unwrap(),
+            std::env::current_dir().unwrap()
+        );
+    }
+
+    #[test]
+    fn base_directory_with_manifest_dir() {
+        assert_eq!(
+            base_directory(Some("/crates/site".into())).unwrap(),
+            std::path::PathBuf::from("/crates/site")
+        );
+    }
+
+    #[test]
+    fn canonicalize_path_with_unset_root_variable() {
+        let result = canonicalize_path("page.html", Some("REMPLATE_UNSET_ROOT_VARIABLE"));
+
+        match result {
+            Err(PathCanonicalizationError::RootVariable { variable, .. }) => {
+                assert_eq!(variable, "REMPLATE_UNSET_ROOT_VARIABLE")
+            }
+            other => panic!("Unexpected result {:?}", other),
+        }
+    }
+
+    #[test]
+    fn read_path_pointer_with_unset_variable() {
+        let result = read_path_pointer("REMPLATE_UNSET_POINTER_VARIABLE");
+
+        match result {
+            Err(PathCanonicalizationError::PathPointerVariable { variable, .. }) => {
+                assert_eq!(variable, "REMPLATE_UNSET_POINTER_VARIABLE")
+            }
+            other => panic!("Unexpected result {:?}", other),
+        }
+    }
+
+    #[test]
+    fn read_path_pointer_with_missing_file() {
+        let result = read_path_pointer("REMPLATE_TEST_MISSING_TEMPLATE_POINTER");
+
+        match result {
+            Err(PathCanonicalizationError::PathPointerFile { path, .. }) => {
+                assert!(path.ends_with("missing_pointer.txt"))
+            }
+            other => panic!("Unexpected result {:?}", other),
+        }
+    }
+
+    #[test]
+    fn canonicalize_path_with_root_variable() {
+        let result = canonicalize_path("Cargo.toml", Some("CARGO_MANIFEST_DIR"));
+
+        assert_eq!(
+            result.unwrap(),
+            std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml")
+        );
+    }
+}
+
+#[cfg(test)]
+mod codegen_snapshot_tests {
+    use std::path::{Path, PathBuf};
+
+    use super::{create_code, TemplateOptions};
+
+    fn pretty_print_code(template: &str) -> String {
+        let code = create_code(
+            template,
+            Path::new("snapshot.html"),
+            TemplateOptions::default(),
+            proc_macro2::Span::call_site(),
+        )
+        .map(|remplate_data| remplate_data.remplate_code)
+        .unwrap_or_else(|error| panic!("Failed to create code - {:?}", error.3));
+
+        let file = syn::parse2::<syn::File>(quote::quote! {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                #include_bytes_part
-                #remplate_code
+                #code
                 Ok(())
             }
+        })
+        .expect("Generated code should be valid Rust");
+
+        prettyplease::unparse(&file)
+    }
+
+    fn assert_snapshot(name: &str, template: &str) {
+        let snapshot_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("snapshots")
+            .join(format!("{}.rs.snap", name));
+        let code = pretty_print_code(template);
+
+        if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+            std::fs::write(&snapshot_path, &code).expect("Snapshot should be writable");
         }
-        impl #impl_generics ::remplate::Remplate for #type_ident #type_generics #where_clause {
-            const ESTIMATED_SIZE: usize = #estimated_template_size;
-        };
+
+        let snapshot = std::fs::read_to_string(&snapshot_path).unwrap_or_else(|error| {
+            panic!(
+                "Unable to read snapshot at {:?} - {} (run with UPDATE_SNAPSHOTS=1 to create it)",
+                snapshot_path, error
+            )
+        });
+
+        assert_eq!(
+            code, snapshot,
+            "Generated code differs from snapshot {:?}",
+            name
+        );
+    }
+
+    #[test]
+    fn snapshot_static_template() {
+        assert_snapshot("static_template", "<h1>Static</h1>\n");
+    }
+
+    #[test]
+    fn snapshot_interpolation() {
+        assert_snapshot("interpolation", "<h1>{ self.title }</h1>\n");
+    }
+
+    #[test]
+    fn snapshot_leading_interpolation() {
+        assert_snapshot(
+            "leading_interpolation",
+            "{ self.title }{ self.subtitle }</h1>",
+        );
+    }
+
+    #[test]
+    fn snapshot_statement_and_interpolation() {
+        assert_snapshot(
+            "statement_and_interpolation",
+            "<p>{ let count = self.items.len(); count:>4}</p>\n",
+        );
     }
-    .into()
 }
@@ -1,8 +1,33 @@
 use quote::ToTokens;
-use syn::{parse::Parse, spanned::Spanned, DeriveInput, LitStr, Token};
+use syn::{
+    parse::Parse, punctuated::Punctuated, spanned::Spanned, DeriveInput, LitBool, LitStr, Token,
+    WherePredicate,
+};
 
 mod kw {
     syn::custom_keyword!(path);
+    syn::custom_keyword!(path_from);
+    syn::custom_keyword!(each);
+    syn::custom_keyword!(root);
+    syn::custom_keyword!(estimated_size_expr);
+    syn::custom_keyword!(context);
+    syn::custom_keyword!(trim_blocks);
+    syn::custom_keyword!(lstrip_blocks);
+    syn::custom_keyword!(bound);
+    syn::custom_keyword!(precompress);
+    syn::custom_keyword!(runtime_escape);
+    syn::custom_keyword!(lint);
+    syn::custom_keyword!(check_html);
+    syn::custom_keyword!(inline_display);
+    syn::custom_keyword!(minify);
+    syn::custom_keyword!(postprocess);
+    syn::custom_keyword!(method);
+    syn::custom_keyword!(glob);
+    syn::custom_keyword!(contextual_escape);
+    syn::custom_keyword!(locale);
+    syn::custom_keyword!(catalog);
+    syn::custom_keyword!(static_only);
+    syn::custom_keyword!(escape);
 }
 
 pub struct RemplatePath(pub String, pub proc_macro2::Span);
@@ -17,23 +42,627 @@ impl Parse for RemplatePath {
     }
 }
 
+pub struct RemplatePathFrom(pub String, pub proc_macro2::Span);
+
+impl Parse for RemplatePathFrom {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        input.parse::<kw::path_from>()?;
+        input.parse::<Token![=]>()?;
+        let path_from_parameter: LitStr = input.parse()?;
+
+        Ok(Self(
+            path_from_parameter.value(),
+            path_from_parameter.span(),
+        ))
+    }
+}
+
+pub struct RemplateEach(pub String, pub proc_macro2::Span);
+
+impl Parse for RemplateEach {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        input.parse::<kw::each>()?;
+        input.parse::<Token![=]>()?;
+        let each_parameter: LitStr = input.parse()?;
+
+        Ok(Self(each_parameter.value(), each_parameter.span()))
+    }
+}
+
+pub enum RemplateTemplatePath {
+    Path(RemplatePath),
+    PathFrom(RemplatePathFrom),
+    Each(RemplateEach),
+}
+
+impl RemplateTemplatePath {
+    pub fn span(&self) -> proc_macro2::Span {
+        match self {
+            RemplateTemplatePath::Path(RemplatePath(_, span))
+            | RemplateTemplatePath::PathFrom(RemplatePathFrom(_, span))
+            | RemplateTemplatePath::Each(RemplateEach(_, span)) => *span,
+        }
+    }
+}
+
+pub struct RemplateRoot(pub String, pub proc_macro2::Span);
+
+impl Parse for RemplateRoot {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        input.parse::<kw::root>()?;
+        input.parse::<Token![=]>()?;
+        let root_parameter: LitStr = input.parse()?;
+
+        Ok(Self(root_parameter.value(), root_parameter.span()))
+    }
+}
+
+pub struct RemplateEstimatedSizeExpr(pub syn::Expr);
+
+impl Parse for RemplateEstimatedSizeExpr {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        input.parse::<kw::estimated_size_expr>()?;
+        input.parse::<Token![=]>()?;
+        let expression_parameter: LitStr = input.parse()?;
+
+        Ok(Self(expression_parameter.parse()?))
+    }
+}
+
+pub struct RemplateContext(pub syn::Type, pub proc_macro2::Span);
+
+impl Parse for RemplateContext {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        input.parse::<kw::context>()?;
+        input.parse::<Token![=]>()?;
+        let context_parameter: LitStr = input.parse()?;
+
+        Ok(Self(context_parameter.parse()?, context_parameter.span()))
+    }
+}
+
+pub struct RemplateTrimBlocks(pub bool);
+
+impl Parse for RemplateTrimBlocks {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        input.parse::<kw::trim_blocks>()?;
+        input.parse::<Token![=]>()?;
+        let trim_blocks_parameter: LitBool = input.parse()?;
+
+        Ok(Self(trim_blocks_parameter.value()))
+    }
+}
+
+pub struct RemplateLstripBlocks(pub bool);
+
+impl Parse for RemplateLstripBlocks {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        input.parse::<kw::lstrip_blocks>()?;
+        input.parse::<Token![=]>()?;
+        let lstrip_blocks_parameter: LitBool = input.parse()?;
+
+        Ok(Self(lstrip_blocks_parameter.value()))
+    }
+}
+
+pub struct RemplateBound(pub Punctuated<WherePredicate, Token![,]>);
+
+impl Parse for RemplateBound {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        input.parse::<kw::bound>()?;
+        input.parse::<Token![=]>()?;
+        let bound_parameter: LitStr = input.parse()?;
+
+        Ok(Self(
+            bound_parameter.parse_with(Punctuated::parse_terminated)?,
+        ))
+    }
+}
+
+pub struct RemplateUse(pub syn::Path);
+
+impl Parse for RemplateUse {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        input.parse::<Token![use]>()?;
+        input.parse::<Token![=]>()?;
+        let use_parameter: LitStr = input.parse()?;
+
+        Ok(Self(use_parameter.parse()?))
+    }
+}
+
+pub struct RemplatePostprocess(pub syn::Path);
+
+impl Parse for RemplatePostprocess {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        input.parse::<kw::postprocess>()?;
+        input.parse::<Token![=]>()?;
+        let postprocess_parameter: LitStr = input.parse()?;
+
+        Ok(Self(postprocess_parameter.parse()?))
+    }
+}
+
+pub struct RemplateMethod(pub syn::Ident, pub proc_macro2::Span);
+
+impl Parse for RemplateMethod {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        input.parse::<kw::method>()?;
+        input.parse::<Token![=]>()?;
+        let method_parameter: LitStr = input.parse()?;
+        let method = method_parameter.parse().map_err(|_| {
+            syn::Error::new(
+                method_parameter.span(),
+                format!(
+                    "Invalid method name `{}`, expected an identifier",
+                    method_parameter.value()
+                ),
+            )
+        })?;
+
+        Ok(Self(method, method_parameter.span()))
+    }
+}
+
+pub struct RemplateGlob(pub String, pub proc_macro2::Span);
+
+impl Parse for RemplateGlob {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        input.parse::<kw::glob>()?;
+        input.parse::<Token![=]>()?;
+        let glob_parameter: LitStr = input.parse()?;
+
+        Ok(Self(glob_parameter.value(), glob_parameter.span()))
+    }
+}
+
+pub struct RemplateEscape(pub &'static str);
+
+impl Parse for RemplateEscape {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        input.parse::<kw::escape>()?;
+        input.parse::<Token![=]>()?;
+        let escape_parameter: LitStr = input.parse()?;
+        let escape_name = escape_parameter.value();
+
+        match crate::filter_parsing::ESCAPE_NAMES
+            .iter()
+            .find(|name| **name == escape_name)
+        {
+            Some(name) => Ok(Self(name)),
+            None => Err(syn::Error::new(
+                escape_parameter.span(),
+                format!(
+                    "Unknown escape `{}`, expected one of {}",
+                    escape_name,
+                    crate::filter_parsing::ESCAPE_NAMES.join(", ")
+                ),
+            )),
+        }
+    }
+}
+
+pub struct RemplatePrecompress(pub String, pub proc_macro2::Span);
+
+impl Parse for RemplatePrecompress {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        input.parse::<kw::precompress>()?;
+        input.parse::<Token![=]>()?;
+        let precompress_parameter: LitStr = input.parse()?;
+
+        Ok(Self(
+            precompress_parameter.value(),
+            precompress_parameter.span(),
+        ))
+    }
+}
+
+pub struct RemplateRuntimeEscape(pub bool);
+
+impl Parse for RemplateRuntimeEscape {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        input.parse::<kw::runtime_escape>()?;
+        input.parse::<Token![=]>()?;
+        let runtime_escape_parameter: LitBool = input.parse()?;
+
+        Ok(Self(runtime_escape_parameter.value()))
+    }
+}
+
+pub struct RemplateLint(pub bool);
+
+impl Parse for RemplateLint {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        input.parse::<kw::lint>()?;
+        input.parse::<Token![=]>()?;
+        let lint_parameter: LitBool = input.parse()?;
+
+        Ok(Self(lint_parameter.value()))
+    }
+}
+
+pub struct RemplateCheckHtml(pub bool);
+
+impl Parse for RemplateCheckHtml {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        input.parse::<kw::check_html>()?;
+        input.parse::<Token![=]>()?;
+        let check_html_parameter: LitBool = input.parse()?;
+
+        Ok(Self(check_html_parameter.value()))
+    }
+}
+
+pub struct RemplateInlineDisplay(pub bool);
+
+impl Parse for RemplateInlineDisplay {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        input.parse::<kw::inline_display>()?;
+        input.parse::<Token![=]>()?;
+        let inline_display_parameter: LitBool = input.parse()?;
+
+        Ok(Self(inline_display_parameter.value()))
+    }
+}
+
+pub struct RemplateMinify(pub bool);
+
+impl Parse for RemplateMinify {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        input.parse::<kw::minify>()?;
+        input.parse::<Token![=]>()?;
+        let minify_parameter: LitBool = input.parse()?;
+
+        Ok(Self(minify_parameter.value()))
+    }
+}
+
+pub struct RemplateLocale(pub String, pub proc_macro2::Span);
+
+impl Parse for RemplateLocale {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        input.parse::<kw::locale>()?;
+        input.parse::<Token![=]>()?;
+        let locale_parameter: LitStr = input.parse()?;
+
+        Ok(Self(locale_parameter.value(), locale_parameter.span()))
+    }
+}
+
+pub struct RemplateCatalog(pub String, pub proc_macro2::Span);
+
+impl Parse for RemplateCatalog {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        input.parse::<kw::catalog>()?;
+        input.parse::<Token![=]>()?;
+        let catalog_parameter: LitStr = input.parse()?;
+
+        Ok(Self(catalog_parameter.value(), catalog_parameter.span()))
+    }
+}
+
+pub struct RemplateStaticOnly(pub bool);
+
+impl Parse for RemplateStaticOnly {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        input.parse::<kw::static_only>()?;
+        input.parse::<Token![=]>()?;
+        let static_only_parameter: LitBool = input.parse()?;
+
+        Ok(Self(static_only_parameter.value()))
+    }
+}
+
+pub struct RemplateContextualEscape(pub bool);
+
+impl Parse for RemplateContextualEscape {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        input.parse::<kw::contextual_escape>()?;
+        input.parse::<Token![=]>()?;
+        let contextual_escape_parameter: LitBool = input.parse()?;
+
+        Ok(Self(contextual_escape_parameter.value()))
+    }
+}
+
+pub struct RemplateAttributes {
+    pub template_path: RemplateTemplatePath,
+    pub template_root: Option<RemplateRoot>,
+    pub estimated_size_expr: Option<RemplateEstimatedSizeExpr>,
+    pub context: Option<RemplateContext>,
+    pub trim_blocks: bool,
+    pub lstrip_blocks: bool,
+    pub bound: Option<RemplateBound>,
+    pub precompress: Option<RemplatePrecompress>,
+    pub runtime_escape: bool,
+    pub lint: bool,
+    pub check_html: bool,
+    pub inline_display: bool,
+    pub minify: bool,
+    pub contextual_escape: bool,
+    pub escape: Option<&'static str>,
+    pub static_only: bool,
+    pub uses: Vec<RemplateUse>,
+    pub postprocess: Option<RemplatePostprocess>,
+    pub method: Option<RemplateMethod>,
+    pub glob: Option<RemplateGlob>,
+    pub locale: Option<RemplateLocale>,
+    pub catalog: Option<RemplateCatalog>,
+}
+
+fn set_option<T>(
+    input: syn::parse::ParseStream,
+    option: &mut Option<T>,
+    value: T,
+    option_name: &str,
+) -> syn::Result<()> {
+    match option.replace(value) {
+        Some(_) => Err(input.error(format!("Duplicate `{}` option", option_name))),
+        None => Ok(()),
+    }
+}
+
+impl Parse for RemplateAttributes {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let mut template_path = None;
+        let mut template_root = None;
+        let mut estimated_size_expr = None;
+        let mut context = None;
+        let mut trim_blocks = None;
+        let mut lstrip_blocks = None;
+        let mut bound = None;
+        let mut precompress = None;
+        let mut runtime_escape = None;
+        let mut lint = None;
+        let mut check_html = None;
+        let mut inline_display = None;
+        let mut minify = None;
+        let mut contextual_escape = None;
+        let mut escape = None;
+        let mut static_only = None;
+        let mut uses = Vec::new();
+        let mut postprocess = None;
+        let mut method = None;
+        let mut glob = None;
+        let mut locale = None;
+        let mut catalog = None;
+
+        while !input.is_empty() {
+            let lookahead = input.lookahead1();
+
+            if lookahead.peek(kw::path) {
+                let path = RemplateTemplatePath::Path(input.parse()?);
+                set_option(input, &mut template_path, path, "path")?;
+            } else if lookahead.peek(kw::path_from) {
+                let path_from = RemplateTemplatePath::PathFrom(input.parse()?);
+                set_option(input, &mut template_path, path_from, "path")?;
+            } else if lookahead.peek(kw::each) {
+                let each = RemplateTemplatePath::Each(input.parse()?);
+                set_option(input, &mut template_path, each, "path")?;
+            } else if lookahead.peek(kw::root) {
+                set_option(input, &mut template_root, input.parse()?, "root")?;
+            } else if lookahead.peek(kw::estimated_size_expr) {
+                set_option(
+                    input,
+                    &mut estimated_size_expr,
+                    input.parse()?,
+                    "estimated_size_expr",
+                )?;
+            } else if lookahead.peek(kw::context) {
+                set_option(input, &mut context, input.parse()?, "context")?;
+            } else if lookahead.peek(kw::trim_blocks) {
+                let RemplateTrimBlocks(value) = input.parse()?;
+                set_option(input, &mut trim_blocks, value, "trim_blocks")?;
+            } else if lookahead.peek(kw::lstrip_blocks) {
+                let RemplateLstripBlocks(value) = input.parse()?;
+                set_option(input, &mut lstrip_blocks, value, "lstrip_blocks")?;
+            } else if lookahead.peek(kw::bound) {
+                set_option(input, &mut bound, input.parse()?, "bound")?;
+            } else if lookahead.peek(kw::precompress) {
+                set_option(input, &mut precompress, input.parse()?, "precompress")?;
+            } else if lookahead.peek(kw::runtime_escape) {
+                let RemplateRuntimeEscape(value) = input.parse()?;
+                set_option(input, &mut runtime_escape, value, "runtime_escape")?;
+            } else if lookahead.peek(kw::lint) {
+                let RemplateLint(value) = input.parse()?;
+                set_option(input, &mut lint, value, "lint")?;
+            } else if lookahead.peek(Token![use]) {
+                uses.push(input.parse()?);
+            } else if lookahead.peek(kw::method) {
+                set_option(input, &mut method, input.parse()?, "method")?;
+            } else if lookahead.peek(kw::locale) {
+                set_option(input, &mut locale, input.parse()?, "locale")?;
+            } else if lookahead.peek(kw::catalog) {
+                set_option(input, &mut catalog, input.parse()?, "catalog")?;
+            } else if lookahead.peek(kw::glob) {
+                set_option(input, &mut glob, input.parse()?, "glob")?;
+            } else if lookahead.peek(kw::postprocess) {
+                set_option(input, &mut postprocess, input.parse()?, "postprocess")?;
+            } else if lookahead.peek(kw::check_html) {
+                let RemplateCheckHtml(value) = input.parse()?;
+                set_option(input, &mut check_html, value, "check_html")?;
+            } else if lookahead.peek(kw::inline_display) {
+                let RemplateInlineDisplay(value) = input.parse()?;
+                set_option(input, &mut inline_display, value, "inline_display")?;
+            } else if lookahead.peek(kw::minify) {
+                let RemplateMinify(value) = input.parse()?;
+                set_option(input, &mut minify, value, "minify")?;
+            } else if lookahead.peek(kw::static_only) {
+                let RemplateStaticOnly(value) = input.parse()?;
+                set_option(input, &mut static_only, value, "static_only")?;
+            } else if lookahead.peek(kw::contextual_escape) {
+                let RemplateContextualEscape(value) = input.parse()?;
+                set_option(input, &mut contextual_escape, value, "contextual_escape")?;
+            } else if lookahead.peek(kw::escape) {
+                let RemplateEscape(value) = input.parse()?;
+                set_option(input, &mut escape, value, "escape")?;
+            } else {
+                return Err(lookahead.error());
+            }
+
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        Ok(Self {
+            template_path: template_path
+                .ok_or_else(|| input.error("Missing template path, expected `path = \"...\"`"))?,
+            template_root,
+            estimated_size_expr,
+            context,
+            trim_blocks: trim_blocks.unwrap_or_default(),
+            lstrip_blocks: lstrip_blocks.unwrap_or_default(),
+            bound,
+            precompress,
+            runtime_escape: runtime_escape.unwrap_or_default(),
+            lint: lint.unwrap_or_default(),
+            check_html: check_html.unwrap_or_default(),
+            inline_display: inline_display.unwrap_or_default(),
+            minify: minify.unwrap_or_default(),
+            contextual_escape: contextual_escape.unwrap_or_default(),
+            escape,
+            static_only: static_only.unwrap_or_default(),
+            uses,
+            postprocess,
+            method,
+            glob,
+            locale,
+            catalog,
+        })
+    }
+}
+
+impl RemplateAttributes {
+    fn validate(&self, has_context_field: bool) -> syn::Result<()> {
+        if let (Some(RemplateMethod(_, method_span)), None, None) =
+            (&self.method, &self.context, &self.glob)
+        {
+            return Err(syn::Error::new(
+                *method_span,
+                "The `method` option requires the `context` or `glob` option",
+            ));
+        }
+
+        if let Some(RemplateGlob(_, glob_span)) = &self.glob {
+            if self.context.is_some() {
+                return Err(syn::Error::new(
+                    *glob_span,
+                    "The `glob` option can not be combined with `context`",
+                ));
+            }
+        }
+
+        if let (Some(RemplatePrecompress(_, precompress_span)), Some(_)) =
+            (&self.precompress, &self.postprocess)
+        {
+            return Err(syn::Error::new(
+                *precompress_span,
+                "The `precompress` option can not be combined with `postprocess`",
+            ));
+        }
+
+        match (&self.locale, &self.catalog) {
+            (Some(RemplateLocale(_, locale_span)), None) => {
+                return Err(syn::Error::new(
+                    *locale_span,
+                    "The `locale` option requires the `catalog` option",
+                ))
+            }
+            (None, Some(RemplateCatalog(_, catalog_span))) => {
+                return Err(syn::Error::new(
+                    *catalog_span,
+                    "The `catalog` option requires the `locale` option",
+                ))
+            }
+            _ => {}
+        }
+
+        let Some(RemplateContext(_, context_span)) = &self.context else {
+            return Ok(());
+        };
+
+        let conflicting_option = [
+            (self.check_html, "`check_html`"),
+            (self.runtime_escape, "`runtime_escape`"),
+            (
+                matches!(self.template_path, RemplateTemplatePath::Each(_)),
+                "`each`",
+            ),
+            (has_context_field, "a `#[remplate_context]` field"),
+            (self.estimated_size_expr.is_some(), "`estimated_size_expr`"),
+            (self.precompress.is_some(), "`precompress`"),
+            (self.postprocess.is_some(), "`postprocess`"),
+        ]
+        .into_iter()
+        .find_map(|(is_set, option)| is_set.then_some(option));
+
+        match conflicting_option {
+            Some(option) => Err(syn::Error::new(
+                *context_span,
+                format!("The `context` option can not be combined with {}", option),
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
+pub struct RenderWithInput {
+    pub template_path: LitStr,
+    pub bindings: Vec<(syn::Ident, proc_macro2::TokenStream)>,
+}
+
+impl Parse for RenderWithInput {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let template_path = input.parse()?;
+        let mut bindings = Vec::new();
+
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+
+            let name: syn::Ident = input.parse()?;
+            input.parse::<Token![=]>()?;
+
+            let mut value = proc_macro2::TokenStream::new();
+            while !input.is_empty() && !input.peek(Token![,]) {
+                value.extend([input.parse::<proc_macro2::TokenTree>()?]);
+            }
+
+            if value.is_empty() {
+                return Err(syn::Error::new(
+                    name.span(),
+                    format!("Missing value for `{}`", name),
+                ));
+            }
+
+            bindings.push((name, value));
+        }
+
+        Ok(Self {
+            template_path,
+            bindings,
+        })
+    }
+}
+
 pub struct MacroParseResult {
     pub impl_generics: proc_macro2::TokenStream,
     pub type_generics: proc_macro2::TokenStream,
     pub where_clause: Option<proc_macro2::TokenStream>,
     pub type_ident: proc_macro2::TokenStream,
-    pub template_path: RemplatePath,
+    pub attributes: RemplateAttributes,
+    pub context_field: Option<proc_macro2::TokenStream>,
 }
 
 pub fn parse_derive_macro_input(
     input: proc_macro::TokenStream,
 ) -> Result<MacroParseResult, syn::Error> {
-    let input = syn::parse::<DeriveInput>(input)?;
-    let input_span = input.span();
-    let impl_type = input.ident;
-    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let mut input = syn::parse::<DeriveInput>(input)?;
 
-    let template_path = match input.attrs.into_iter().find(|attr| {
+    let mut attributes = match input.attrs.iter().find(|attr| {
         let attr_path = attr.meta.path();
         attr_path.is_ident("remplate")
     }) {
@@ -41,15 +670,81 @@ pub fn parse_derive_macro_input(
             .meta
             .require_list()
             .map(|meta_list| meta_list.tokens.clone())
-            .and_then(syn::parse2::<RemplatePath>)?,
-        None => Err(syn::parse::Error::new(input_span, "Missing template path"))?,
+            .and_then(syn::parse2::<RemplateAttributes>)?,
+        None => Err(syn::parse::Error::new(
+            input.ident.span(),
+            "Missing template path, expected an attribute like `#[remplate(path = \"...\")]`",
+        ))?,
     };
 
+    if let Some(RemplateBound(predicates)) = attributes.bound.take() {
+        input
+            .generics
+            .make_where_clause()
+            .predicates
+            .extend(predicates);
+    }
+
+    if let RemplateTemplatePath::Each(_) = attributes.template_path {
+        let is_newtype = matches!(
+            &input.data,
+            syn::Data::Struct(syn::DataStruct {
+                fields: syn::Fields::Unnamed(fields),
+                ..
+            }) if fields.unnamed.len() == 1
+        );
+
+        if !is_newtype {
+            return Err(syn::Error::new(
+                input.ident.span(),
+                "The `each` option requires a struct with a single unnamed field",
+            ));
+        }
+    }
+
+    let context_field = parse_context_field(&input.data)?;
+    attributes.validate(context_field.is_some())?;
+
+    let impl_type = input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
     Ok(MacroParseResult {
         impl_generics: impl_generics.to_token_stream(),
         type_generics: ty_generics.to_token_stream(),
         where_clause: where_clause.map(|where_clause| where_clause.to_token_stream()),
         type_ident: impl_type.to_token_stream(),
-        template_path,
+        attributes,
+        context_field,
     })
 }
+
+fn parse_context_field(data: &syn::Data) -> Result<Option<proc_macro2::TokenStream>, syn::Error> {
+    let syn::Data::Struct(data_struct) = data else {
+        return Ok(None);
+    };
+
+    let mut context_fields = data_struct
+        .fields
+        .iter()
+        .enumerate()
+        .filter(|(_, field)| {
+            field
+                .attrs
+                .iter()
+                .any(|attr| attr.path().is_ident("remplate_context"))
+        })
+        .map(|(index, field)| match &field.ident {
+            Some(ident) => ident.to_token_stream(),
+            None => syn::Index::from(index).to_token_stream(),
+        });
+
+    let context_field = context_fields.next();
+
+    match context_fields.next() {
+        Some(second_context_field) => Err(syn::Error::new(
+            second_context_field.span(),
+            "Only one field can be marked with `#[remplate_context]`",
+        )),
+        None => Ok(context_field),
+    }
+}
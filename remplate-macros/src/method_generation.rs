@@ -0,0 +1,500 @@
+use crate::{
+    canonicalize_path, create_include_bytes, glob_parsing, handle_template,
+    macro_parsing::RemplateGlob, read_template, rendered_code, RemplateData, TemplateOptions,
+    RESERVED_REGION_NAMES,
+};
+
+pub struct TemplateImpl {
+    pub impl_generics: proc_macro2::TokenStream,
+    pub type_generics: proc_macro2::TokenStream,
+    pub where_clause: Option<proc_macro2::TokenStream>,
+    pub type_ident: proc_macro2::TokenStream,
+    pub use_part: proc_macro2::TokenStream,
+    pub context_field_part: Option<proc_macro2::TokenStream>,
+    pub is_each: bool,
+    pub span: proc_macro2::Span,
+}
+
+impl TemplateImpl {
+    fn inherent_impl(
+        &self,
+        span: proc_macro2::Span,
+        methods: proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        let TemplateImpl {
+            impl_generics,
+            type_generics,
+            where_clause,
+            type_ident,
+            ..
+        } = self;
+
+        quote::quote_spanned! { span =>
+            impl #impl_generics #type_ident #type_generics #where_clause {
+                #methods
+            }
+        }
+    }
+
+    pub fn precompressed_method(&self, compressed_template: &[u8]) -> proc_macro2::TokenStream {
+        let compressed_template = proc_macro2::Literal::byte_string(compressed_template);
+
+        self.inherent_impl(
+            self.span,
+            quote::quote_spanned! { self.span =>
+                pub const COMPRESSED: &'static [u8] = #compressed_template;
+
+                pub fn compressed(&self) -> &'static [u8] {
+                    Self::COMPRESSED
+                }
+            },
+        )
+    }
+
+    pub fn span_recording_method(
+        &self,
+        span_recording_code: proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        let TemplateImpl {
+            use_part,
+            context_field_part,
+            ..
+        } = self;
+
+        self.inherent_impl(
+            self.span,
+            quote::quote_spanned! { self.span =>
+                #[allow(dead_code)]
+                pub fn render_with_spans(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::string::String,
+                        ::std::vec::Vec<::core::ops::Range<usize>>,
+                    ),
+                    ::core::fmt::Error,
+                > {
+                    let mut rendered = ::std::string::String::with_capacity(
+                        ::remplate::Remplate::estimated_size(self),
+                    );
+                    let mut __remplate_spans = ::std::vec::Vec::new();
+
+                    {
+                        let f = &mut ::remplate::SpanWriter(&mut rendered);
+                        #use_part
+                        #context_field_part
+                        #span_recording_code
+                    }
+
+                    ::core::result::Result::Ok((rendered, __remplate_spans))
+                }
+            },
+        )
+    }
+
+    pub fn runtime_escape_method(
+        &self,
+        runtime_escaped_code: proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        let TemplateImpl {
+            use_part,
+            context_field_part,
+            ..
+        } = self;
+
+        self.inherent_impl(
+            self.span,
+            quote::quote_spanned! { self.span =>
+                pub fn render_escaped(
+                    &self,
+                    escape: bool,
+                ) -> ::core::result::Result<::std::string::String, ::core::fmt::Error> {
+                    let __remplate_escape = escape;
+                    let template = ::remplate::InlineTemplate(
+                        |f: &mut ::core::fmt::Formatter<'_>| -> ::core::fmt::Result {
+                            let _ = __remplate_escape;
+                            #use_part
+                            #context_field_part
+                            #runtime_escaped_code
+                            ::core::result::Result::Ok(())
+                        }
+                    );
+
+                    let mut rendered = ::std::string::String::with_capacity(
+                        ::remplate::Remplate::estimated_size(self),
+                    );
+                    ::core::fmt::Write::write_fmt(&mut rendered, format_args!("{}", template))?;
+
+                    ::core::result::Result::Ok(<Self as ::remplate::Remplate>::postprocess(rendered))
+                }
+            },
+        )
+    }
+
+    pub fn region_method(
+        &self,
+        region_name: &str,
+        region_code: proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        let TemplateImpl {
+            use_part,
+            context_field_part,
+            ..
+        } = self;
+        let method = proc_macro2::Ident::new(&format!("render_{}", region_name), self.span);
+
+        self.inherent_impl(
+            self.span,
+            quote::quote_spanned! { self.span =>
+                #[allow(dead_code, unused_variables)]
+                pub fn #method(
+                    &self,
+                ) -> ::core::result::Result<::std::string::String, ::core::fmt::Error> {
+                    let template = ::remplate::InlineTemplate(
+                        |f: &mut ::core::fmt::Formatter<'_>| -> ::core::fmt::Result {
+                            #use_part
+                            #context_field_part
+                            #region_code
+                            ::core::result::Result::Ok(())
+                        }
+                    );
+
+                    let mut rendered = ::std::string::String::new();
+                    ::core::fmt::Write::write_fmt(&mut rendered, format_args!("{}", template))?;
+
+                    ::core::result::Result::Ok(<Self as ::remplate::Remplate>::postprocess(rendered))
+                }
+            },
+        )
+    }
+
+    pub fn region_info_method(
+        &self,
+        region_names: &[String],
+        region_info_code: proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        let TemplateImpl {
+            use_part,
+            context_field_part,
+            ..
+        } = self;
+        let region_count = region_names.len();
+
+        self.inherent_impl(
+            self.span,
+            quote::quote_spanned! { self.span =>
+                #[allow(dead_code)]
+                pub fn render_with_region_info(
+                    &self,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::string::String,
+                        ::std::collections::HashMap<&'static str, bool>,
+                    ),
+                    ::core::fmt::Error,
+                > {
+                    let mut rendered = ::std::string::String::with_capacity(
+                        ::remplate::Remplate::estimated_size(self),
+                    );
+                    let mut __remplate_region_starts = [0usize; #region_count];
+                    let mut __remplate_regions = [false; #region_count];
+
+                    {
+                        let f = &mut ::remplate::OffsetWriter::new(&mut rendered);
+                        #use_part
+                        #context_field_part
+                        #region_info_code
+                    }
+
+                    let region_info = [#(#region_names),*]
+                        .into_iter()
+                        .zip(__remplate_regions)
+                        .collect();
+
+                    ::core::result::Result::Ok((<Self as ::remplate::Remplate>::postprocess(rendered), region_info))
+                }
+            },
+        )
+    }
+
+    pub fn debug_render_method(
+        &self,
+        annotated_code: proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        let TemplateImpl {
+            use_part,
+            context_field_part,
+            ..
+        } = self;
+
+        self.inherent_impl(
+            self.span,
+            quote::quote_spanned! { self.span =>
+                #[allow(dead_code)]
+                pub fn render_debug(
+                    &self,
+                ) -> ::core::result::Result<::std::string::String, ::core::fmt::Error> {
+                    let template = ::remplate::InlineTemplate(
+                        |f: &mut ::core::fmt::Formatter<'_>| -> ::core::fmt::Result {
+                            #use_part
+                            #context_field_part
+                            #annotated_code
+                            ::core::result::Result::Ok(())
+                        }
+                    );
+
+                    let mut rendered = ::std::string::String::with_capacity(
+                        ::remplate::Remplate::estimated_size(self),
+                    );
+                    ::core::fmt::Write::write_fmt(&mut rendered, format_args!("{}", template))?;
+
+                    ::core::result::Result::Ok(<Self as ::remplate::Remplate>::postprocess(rendered))
+                }
+            },
+        )
+    }
+
+    pub fn context_methods(
+        &self,
+        context_type: &syn::Type,
+        method: &proc_macro2::Ident,
+        display_fmt: proc_macro2::TokenStream,
+        estimated_template_size: usize,
+        span_recording_code: Option<proc_macro2::TokenStream>,
+        annotated_code: Option<proc_macro2::TokenStream>,
+    ) -> proc_macro2::TokenStream {
+        let TemplateImpl { use_part, .. } = self;
+
+        let span_recording_part = span_recording_code.map(|span_recording_code| {
+            let method = quote::format_ident!("{}_with_spans", method);
+
+            quote::quote_spanned! { self.span =>
+                #[allow(dead_code)]
+                pub fn #method(
+                    ctx: &#context_type,
+                ) -> ::core::result::Result<
+                    (
+                        ::std::string::String,
+                        ::std::vec::Vec<::core::ops::Range<usize>>,
+                    ),
+                    ::core::fmt::Error,
+                > {
+                    let mut rendered = ::std::string::String::with_capacity(#estimated_template_size);
+                    let mut __remplate_spans = ::std::vec::Vec::new();
+
+                    {
+                        let f = &mut ::remplate::SpanWriter(&mut rendered);
+                        let _ = ctx;
+                        #use_part
+                        #span_recording_code
+                    }
+
+                    ::core::result::Result::Ok((rendered, __remplate_spans))
+                }
+            }
+        });
+
+        let debug_render_part = annotated_code.map(|annotated_code| {
+            let method = quote::format_ident!("{}_debug", method);
+
+            quote::quote_spanned! { self.span =>
+                #[allow(dead_code)]
+                pub fn #method(
+                    ctx: &#context_type,
+                ) -> ::core::result::Result<::std::string::String, ::core::fmt::Error> {
+                    let template = ::remplate::InlineTemplate(
+                        |f: &mut ::core::fmt::Formatter<'_>| -> ::core::fmt::Result {
+                            let _ = ctx;
+                            #use_part
+                            #annotated_code
+                            ::core::result::Result::Ok(())
+                        }
+                    );
+
+                    let mut rendered = ::std::string::String::with_capacity(#estimated_template_size);
+                    ::core::fmt::Write::write_fmt(&mut rendered, format_args!("{}", template))?;
+
+                    ::core::result::Result::Ok(rendered)
+                }
+            }
+        });
+
+        self.inherent_impl(
+            self.span,
+            quote::quote_spanned! { self.span =>
+                pub fn #method(
+                    ctx: &#context_type,
+                ) -> ::core::result::Result<::std::string::String, ::core::fmt::Error> {
+                    struct RemplateContext<'remplate>(&'remplate #context_type);
+
+                    impl ::core::fmt::Display for RemplateContext<'_> {
+                        #display_fmt
+                    }
+
+                    let mut rendered = ::std::string::String::with_capacity(#estimated_template_size);
+                    ::core::fmt::Write::write_fmt(
+                        &mut rendered,
+                        format_args!("{}", RemplateContext(ctx)),
+                    )?;
+
+                    ::core::result::Result::Ok(rendered)
+                }
+
+                #span_recording_part
+                #debug_render_part
+            },
+        )
+    }
+
+    pub fn glob_method(
+        &self,
+        RemplateGlob(glob, glob_span): &RemplateGlob,
+        template_root: Option<&str>,
+        method: Option<&syn::Ident>,
+        template_options: TemplateOptions,
+        region_names: &[String],
+    ) -> syn::Result<proc_macro2::TokenStream> {
+        let TemplateImpl {
+            use_part,
+            context_field_part,
+            is_each,
+            ..
+        } = self;
+        let glob_span = *glob_span;
+
+        let Some((directory, file_pattern)) = glob_parsing::split_glob(glob) else {
+            return Err(syn::Error::new(
+                glob_span,
+                format!(
+                    "Invalid glob `{}`, only the file name may contain wildcards",
+                    glob
+                ),
+            ));
+        };
+
+        let glob_paths = canonicalize_path(directory, template_root)
+            .map_err(|error| error.to_string())
+            .and_then(|glob_directory| {
+                glob_parsing::expand_glob(&glob_directory, file_pattern).map_err(|error| {
+                    format!(
+                        "Unable to list templates at {:?} - {}",
+                        glob_directory, error
+                    )
+                })
+            })
+            .map_err(|message| syn::Error::new(glob_span, message))?;
+        if glob_paths.is_empty() {
+            return Err(syn::Error::new(
+                glob_span,
+                format!("No templates match the glob `{}`", glob),
+            ));
+        }
+
+        let mut variant_names = Vec::new();
+        let mut variant_arms = proc_macro2::TokenStream::new();
+        for glob_path in &glob_paths {
+            let variant_name = glob_path
+                .file_stem()
+                .and_then(|file_stem| file_stem.to_str())
+                .unwrap_or_default();
+
+            if variant_names.contains(&variant_name) {
+                return Err(syn::Error::new(
+                    glob_span,
+                    format!(
+                        "Multiple templates named `{}` match the glob `{}`",
+                        variant_name, glob
+                    ),
+                ));
+            }
+            variant_names.push(variant_name);
+
+            let variant_template = read_template(glob_path, glob_span)?;
+            let RemplateData {
+                remplate_code,
+                included_paths,
+                uses_offset,
+                ..
+            } = handle_template(&variant_template, glob_path, template_options, glob_span)?;
+            let remplate_code = rendered_code(remplate_code, *is_each, uses_offset, glob_span);
+
+            let include_bytes_part: proc_macro2::TokenStream = std::iter::once(glob_path)
+                .chain(&included_paths)
+                .map(|path| create_include_bytes(path))
+                .collect();
+
+            variant_arms.extend(quote::quote_spanned! { glob_span =>
+                #variant_name => {
+                    #include_bytes_part
+                    let template = ::remplate::InlineTemplate(
+                        |f: &mut ::core::fmt::Formatter<'_>| -> ::core::fmt::Result {
+                            #use_part
+                            #context_field_part
+                            #remplate_code
+                            ::core::result::Result::Ok(())
+                        }
+                    );
+
+                    ::core::fmt::Write::write_fmt(&mut rendered, format_args!("{}", template))
+                }
+            });
+        }
+
+        let method = match method {
+            Some(method) => proc_macro2::Ident::new(&method.to_string(), glob_span),
+            None => match syn::parse_str::<syn::Ident>(&glob_parsing::method_name(directory)) {
+                Ok(method) => proc_macro2::Ident::new(&method.to_string(), glob_span),
+                Err(_) => {
+                    return Err(syn::Error::new(
+                        glob_span,
+                        format!(
+                            "Unable to name the render method after the directory of the glob `{}`, use the `method` option",
+                            glob
+                        ),
+                    ))
+                }
+            },
+        };
+
+        let is_colliding = method
+            .to_string()
+            .strip_prefix("render_")
+            .is_some_and(|name| {
+                region_names.iter().any(|region| region == name)
+                    || RESERVED_REGION_NAMES.contains(&name)
+            });
+        if is_colliding {
+            return Err(syn::Error::new(
+                glob_span,
+                format!(
+                    "The render method `{}` of the glob collides with another render method",
+                    method
+                ),
+            ));
+        }
+
+        Ok(self.inherent_impl(
+            glob_span,
+            quote::quote_spanned! { glob_span =>
+                #[allow(unused_variables)]
+                pub fn #method(
+                    &self,
+                    name: &str,
+                ) -> ::core::option::Option<
+                    ::core::result::Result<::std::string::String, ::core::fmt::Error>,
+                > {
+                    let mut rendered = ::std::string::String::with_capacity(
+                        ::remplate::Remplate::estimated_size(self),
+                    );
+
+                    let result = match name {
+                        #variant_arms
+                        _ => return ::core::option::Option::None,
+                    };
+
+                    ::core::option::Option::Some(result.map(|_| <Self as ::remplate::Remplate>::postprocess(rendered)))
+                }
+            },
+        ))
+    }
+}
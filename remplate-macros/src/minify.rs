@@ -0,0 +1,134 @@
+const PREFORMATTED_ELEMENTS: [&str; 4] = ["pre", "textarea", "script", "style"];
+
+fn collapse_whitespace(output: &mut String, fragment: &str) {
+    let mut is_whitespace_run = false;
+
+    for character in fragment.chars() {
+        match character.is_whitespace() {
+            true if is_whitespace_run => {}
+            true => {
+                is_whitespace_run = true;
+                output.push(' ');
+            }
+            false => {
+                is_whitespace_run = false;
+                output.push(character);
+            }
+        }
+    }
+}
+
+fn find_opening_tag(lowercase_fragment: &str, start: usize) -> Option<(usize, &'static str)> {
+    PREFORMATTED_ELEMENTS
+        .iter()
+        .filter_map(|element| {
+            let tag = format!("<{}", element);
+
+            lowercase_fragment[start..]
+                .match_indices(&tag)
+                .map(|(position, _)| start + position + tag.len())
+                .find(|tag_end| {
+                    lowercase_fragment[*tag_end..]
+                        .chars()
+                        .next()
+                        .is_none_or(|character| {
+                            character == '>' || character == '/' || character.is_whitespace()
+                        })
+                })
+                .map(|tag_end| (tag_end, *element))
+        })
+        .min_by_key(|(tag_end, _)| *tag_end)
+}
+
+pub fn minify_fragment(fragment: &str, preformatted_element: &mut Option<&'static str>) -> String {
+    let lowercase_fragment = fragment.to_ascii_lowercase();
+    let mut output = String::with_capacity(fragment.len());
+    let mut position = 0;
+
+    loop {
+        match preformatted_element {
+            Some(element) => match lowercase_fragment[position..].find(&format!("</{}", element)) {
+                Some(closing_tag_start) => {
+                    output.push_str(&fragment[position..(position + closing_tag_start)]);
+                    position += closing_tag_start;
+                    *preformatted_element = None;
+                }
+                None => {
+                    output.push_str(&fragment[position..]);
+                    break;
+                }
+            },
+            None => match find_opening_tag(&lowercase_fragment, position) {
+                Some((tag_end, element)) => {
+                    collapse_whitespace(&mut output, &fragment[position..tag_end]);
+                    position = tag_end;
+                    *preformatted_element = Some(element);
+                }
+                None => {
+                    collapse_whitespace(&mut output, &fragment[position..]);
+                    break;
+                }
+            },
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod minify_tests {
+    use super::minify_fragment;
+
+    #[test]
+    fn collapse_whitespace_runs() {
+        let mut preformatted_element = None;
+
+        assert_eq!(
+            minify_fragment(
+                "<ul>\n    <li>a</li>\n\t<li>b</li>\n</ul>\n",
+                &mut preformatted_element
+            ),
+            "<ul> <li>a</li> <li>b</li> </ul> "
+        );
+    }
+
+    #[test]
+    fn keep_preformatted_elements() {
+        let mut preformatted_element = None;
+
+        assert_eq!(
+            minify_fragment(
+                "<div>\n  <PRE class=\"code\">  a\n    b</PRE>\n  <textarea>x  y</textarea>  </div>",
+                &mut preformatted_element
+            ),
+            "<div> <PRE class=\"code\">  a\n    b</PRE> <textarea>x  y</textarea> </div>"
+        );
+        assert_eq!(preformatted_element, None);
+    }
+
+    #[test]
+    fn keep_preformatted_elements_across_fragments() {
+        let mut preformatted_element = None;
+
+        assert_eq!(
+            minify_fragment("<p>\n</p><script>\n  let a", &mut preformatted_element),
+            "<p> </p><script>\n  let a"
+        );
+        assert_eq!(preformatted_element, Some("script"));
+        assert_eq!(
+            minify_fragment(";\n</script>\n\n<p>", &mut preformatted_element),
+            ";\n</script> <p>"
+        );
+        assert_eq!(preformatted_element, None);
+    }
+
+    #[test]
+    fn ignore_elements_with_preformatted_prefix() {
+        let mut preformatted_element = None;
+
+        assert_eq!(
+            minify_fragment("<preview>\n  a  </preview>", &mut preformatted_element),
+            "<preview> a </preview>"
+        );
+    }
+}
@@ -0,0 +1,972 @@
+use std::{iter::Peekable, ops::Range};
+
+#[derive(PartialEq, Eq, Debug, Default, Clone)]
+pub struct ParseResult {
+    pub code_block_fragment_ranges: Vec<Range<usize>>,
+    pub template_fragment_ranges: Vec<Range<usize>>,
+}
+
+#[derive(PartialEq, Eq, Debug)]
+pub enum TemplateParseError {
+    CodeBlockHasNoEnd { position: usize },
+    StrHasNoEnd { position: usize },
+}
+
+impl TemplateParseError {
+    pub fn position(&self) -> usize {
+        match self {
+            TemplateParseError::CodeBlockHasNoEnd { position } => *position,
+            TemplateParseError::StrHasNoEnd { position } => *position,
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Debug)]
+pub enum Fragment<'a> {
+    Static(&'a str),
+    Dynamic(&'a str),
+}
+
+impl ParseResult {
+    pub fn fragments<'a>(&self, template: &'a str) -> Vec<Fragment<'a>> {
+        let mut fragments = Vec::new();
+        let mut template_fragment_ranges = self.template_fragment_ranges.iter();
+
+        for code_block_range in &self.code_block_fragment_ranges {
+            if let Some(template_fragment_range) = template_fragment_ranges.next() {
+                push_static_fragment(&mut fragments, &template[template_fragment_range.clone()]);
+            }
+
+            let code_block = &template[code_block_range.clone()];
+            if !is_comment(code_block) && !code_block.trim().is_empty() {
+                fragments.push(Fragment::Dynamic(code_block.trim()));
+            }
+        }
+
+        for template_fragment_range in template_fragment_ranges {
+            push_static_fragment(&mut fragments, &template[template_fragment_range.clone()]);
+        }
+
+        fragments
+    }
+}
+
+fn push_static_fragment<'a>(fragments: &mut Vec<Fragment<'a>>, template_fragment: &'a str) {
+    if !template_fragment.is_empty() {
+        fragments.push(Fragment::Static(template_fragment));
+    }
+}
+
+pub fn is_comment(code_block: &str) -> bool {
+    (code_block.starts_with('#') && code_block.ends_with('#'))
+        || directive_end(code_block, "comment").is_some()
+}
+
+fn directive_end(block: &str, name: &str) -> Option<usize> {
+    let rest = block.trim_start().strip_prefix('%')?;
+    let rest = rest.trim_start().strip_prefix(name)?;
+    let rest = rest.trim_start().strip_prefix('%')?.trim_start();
+
+    rest.starts_with('}').then(|| block.len() - rest.len())
+}
+
+fn block_comment_end(input: &str) -> Option<Result<usize, CodeBlockParseError>> {
+    let opening_end = 1 + directive_end(&input[1..], "comment")?;
+
+    let closing_end = input[opening_end..]
+        .match_indices('{')
+        .find_map(|(position, _)| {
+            let block_start = opening_end + position + 1;
+            directive_end(&input[block_start..], "endcomment").map(|end| block_start + end)
+        });
+
+    Some(closing_end.ok_or(CodeBlockParseError::BlockHasNoEnd))
+}
+
+pub fn parse_fragments(input: &str) -> Result<Vec<Fragment<'_>>, TemplateParseError> {
+    parse_template(input).map(|result| result.fragments(input))
+}
+
+pub fn parse_template(input: &str) -> Result<ParseResult, TemplateParseError> {
+    let mut result = ParseResult::default();
+    let mut iterator = input.char_indices().peekable();
+
+    while let Some((index, character)) = iterator.next() {
+        if character == '{' {
+            match parse_code_block(&input[index..]) {
+                Ok(block_end) => {
+                    match result.code_block_fragment_ranges.last() {
+                        Some(last_block) => {
+                            result
+                                .template_fragment_ranges
+                                .push((last_block.end + 1)..index);
+                        }
+                        None => {
+                            result.template_fragment_ranges.push(0..index);
+                        }
+                    }
+
+                    result
+                        .code_block_fragment_ranges
+                        .push((index + 1)..(block_end + index));
+
+                    skip_until(&mut iterator, index + block_end + 1);
+                }
+                Err(error) => match error {
+                    CodeBlockParseError::StrHasNoEnd { start } => {
+                        return Err(TemplateParseError::StrHasNoEnd {
+                            position: start + index,
+                        })
+                    }
+                    CodeBlockParseError::BlockHasNoEnd => {
+                        return Err(TemplateParseError::CodeBlockHasNoEnd { position: index })
+                    }
+                    CodeBlockParseError::Escaped => continue,
+                },
+            }
+        }
+    }
+
+    match result.code_block_fragment_ranges.last() {
+        Some(last_block) => result
+            .template_fragment_ranges
+            .push((last_block.end + 1)..input.len()),
+        None => result.template_fragment_ranges.push(0..input.len()),
+    }
+
+    Ok(result)
+}
+
+#[derive(PartialEq, Eq, Debug)]
+pub enum CodeBlockParseError {
+    StrHasNoEnd { start: usize },
+    BlockHasNoEnd,
+    Escaped,
+}
+
+fn skip_until<I>(iterator: &mut Peekable<I>, position: usize)
+where
+    I: Iterator<Item = (usize, char)>,
+{
+    while iterator.next_if(|(index, _)| *index < position).is_some() {}
+}
+
+fn parse_code_block(input: &str) -> Result<usize, CodeBlockParseError> {
+    if let Some(comment_end) = block_comment_end(input) {
+        return comment_end;
+    }
+
+    let mut iterator = input.char_indices().peekable();
+    let mut open_delimiters = 0;
+
+    while let Some((index, character)) = iterator.next() {
+        match character {
+            '#' if index == 1 => {
+                return match input[2..].find("#}") {
+                    Some(comment_end) => Ok(comment_end + 3),
+                    None => Err(CodeBlockParseError::BlockHasNoEnd),
+                };
+            }
+            '{' => {
+                if index == 1 {
+                    return Err(CodeBlockParseError::Escaped);
+                } else if index > 0 {
+                    if open_delimiters == 0 && is_scope_header(&input[1..index]) {
+                        if let Some(end) = scope_opening_end(&input[(index + 1)..]) {
+                            return Ok(index + 1 + end);
+                        }
+                    }
+
+                    open_delimiters += 1;
+                }
+            }
+            'r' | '"' => match parse_str_literal(&input[index..]) {
+                Ok(str_range) => {
+                    skip_until(&mut iterator, index + str_range.end + 1);
+                }
+                Err(StrLiteralParseError::NoStrFound) => continue,
+                Err(StrLiteralParseError::StrHasNoEnd { start }) => {
+                    return Err(CodeBlockParseError::StrHasNoEnd {
+                        start: start + index,
+                    })
+                }
+            },
+            '}' => {
+                if open_delimiters == 0 {
+                    if input[1..index].trim().is_empty() && is_scope_closing(&input[(index + 1)..])
+                    {
+                        continue;
+                    }
+
+                    return Ok(index);
+                } else {
+                    open_delimiters -= 1;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Err(CodeBlockParseError::BlockHasNoEnd)
+}
+
+const SCOPE_KEYWORDS: [&str; 5] = ["if", "else", "for", "while", "loop"];
+
+fn starts_with_keyword(input: &str, keyword: &str) -> bool {
+    input.starts_with(keyword)
+        && !input[keyword.len()..]
+            .starts_with(|character: char| character.is_alphanumeric() || character == '_')
+}
+
+fn is_scope_header(block_content: &str) -> bool {
+    let block_content = block_content.trim_start();
+
+    block_content.starts_with('}')
+        || SCOPE_KEYWORDS
+            .iter()
+            .any(|keyword| starts_with_keyword(block_content, keyword))
+}
+
+fn scope_opening_end(input: &str) -> Option<usize> {
+    let whitespace_length = input.len() - input.trim_start().len();
+
+    match input[whitespace_length..].starts_with('}') {
+        true => Some(whitespace_length),
+        false => None,
+    }
+}
+
+fn is_scope_closing(input: &str) -> bool {
+    let input = input.trim_start();
+
+    input.starts_with('}') || is_else_continuation(input)
+}
+
+fn is_else_continuation(input: &str) -> bool {
+    if !starts_with_keyword(input, "else") {
+        return false;
+    }
+
+    let continuation = input["else".len()..].trim_start();
+
+    continuation.starts_with('{') || starts_with_keyword(continuation, "if")
+}
+
+pub fn find_unbalanced_delimiter(code_block: &str) -> Option<usize> {
+    let mut iterator = code_block.char_indices().peekable();
+    let mut open_delimiters = Vec::new();
+
+    while let Some((index, character)) = iterator.next() {
+        match character {
+            '(' | '[' => open_delimiters.push((index, character)),
+            ')' | ']' => match (open_delimiters.pop(), character) {
+                (Some((_, '(')), ')') | (Some((_, '[')), ']') => {}
+                _ => return Some(index),
+            },
+            '\'' => {
+                if let Some(length) = char_literal_length(&code_block[index..]) {
+                    skip_until(&mut iterator, index + length);
+                }
+            }
+            'r' | '"' => {
+                if let Ok(str_range) = parse_str_literal(&code_block[index..]) {
+                    skip_until(&mut iterator, index + str_range.end + 1);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    open_delimiters.last().map(|(index, _)| *index)
+}
+
+fn char_literal_length(input: &str) -> Option<usize> {
+    let mut characters = input.char_indices().skip(1);
+
+    match characters.next()? {
+        (_, '\\') => {
+            characters.next()?;
+            characters
+                .find(|(_, character)| *character == '\'')
+                .map(|(index, _)| index + 1)
+        }
+        _ => match characters.next()? {
+            (index, '\'') => Some(index + 1),
+            _ => None,
+        },
+    }
+}
+
+#[derive(Debug)]
+struct StringMatch {
+    position: usize,
+    length: usize,
+}
+
+#[derive(Debug)]
+enum StringMatchState {
+    MatchingFirst(StringMatch),
+    MatchingSecond {
+        first: StringMatch,
+        second: Option<StringMatch>,
+    },
+}
+
+#[derive(PartialEq, Eq, Debug)]
+pub enum StrLiteralParseError {
+    NoStrFound,
+    StrHasNoEnd { start: usize },
+}
+
+fn parse_str_literal(input: &str) -> Result<Range<usize>, StrLiteralParseError> {
+    let mut parse_state = None;
+    let mut is_escaped = false;
+
+    for (index, character) in input.char_indices() {
+        if is_escaped {
+            is_escaped = false;
+            continue;
+        }
+
+        if let (
+            '\\',
+            Some(StringMatchState::MatchingSecond {
+                first,
+                second: None,
+            }),
+        ) = (character, &parse_state)
+        {
+            if input[first.position..].starts_with('"') {
+                is_escaped = true;
+                continue;
+            }
+        }
+
+        match character {
+            'r' => match parse_state {
+                None | Some(StringMatchState::MatchingFirst(_)) => {
+                    parse_state = Some(StringMatchState::MatchingFirst(StringMatch {
+                        position: index,
+                        length: 0,
+                    }));
+                }
+                Some(StringMatchState::MatchingSecond { .. }) => continue,
+            },
+            '#' => match parse_state {
+                Some(StringMatchState::MatchingFirst(first)) => {
+                    parse_state = Some(StringMatchState::MatchingFirst(StringMatch {
+                        position: first.position,
+                        length: first.length + 1,
+                    }))
+                }
+                Some(StringMatchState::MatchingSecond {
+                    first: first_match,
+                    second: Some(second_match),
+                }) if first_match.length == (second_match.length + 1) => {
+                    parse_state = Some(StringMatchState::MatchingSecond {
+                        first: first_match,
+                        second: Some(StringMatch {
+                            position: second_match.position,
+                            length: second_match.length + 1,
+                        }),
+                    });
+                    break;
+                }
+                Some(StringMatchState::MatchingSecond {
+                    first,
+                    second: Some(second),
+                }) => {
+                    parse_state = Some(StringMatchState::MatchingSecond {
+                        first,
+                        second: Some(StringMatch {
+                            position: second.position,
+                            length: second.length + 1,
+                        }),
+                    })
+                }
+                None | Some(_) => {}
+            },
+            '"' => {
+                match parse_state {
+                    Some(StringMatchState::MatchingFirst(first)) => {
+                        parse_state = Some(StringMatchState::MatchingSecond {
+                            first: StringMatch {
+                                position: first.position,
+                                length: first.length,
+                            },
+                            second: None,
+                        })
+                    }
+                    Some(StringMatchState::MatchingSecond {
+                        first,
+                        second: None,
+                    }) if first.length == 0 => {
+                        parse_state = Some(StringMatchState::MatchingSecond {
+                            first,
+                            second: Some(StringMatch {
+                                position: index,
+                                length: 0,
+                            }),
+                        });
+
+                        break;
+                    }
+                    Some(StringMatchState::MatchingSecond {
+                        first,
+                        second: None | Some(_),
+                    }) => {
+                        parse_state = Some(StringMatchState::MatchingSecond {
+                            first,
+                            second: Some(StringMatch {
+                                position: index,
+                                length: 0,
+                            }),
+                        });
+                    }
+                    None => {
+                        parse_state = Some(StringMatchState::MatchingSecond {
+                            first: StringMatch {
+                                position: index,
+                                length: 0,
+                            },
+                            second: None,
+                        })
+                    }
+                };
+            }
+            _ => match parse_state {
+                Some(StringMatchState::MatchingFirst(_)) => break,
+                Some(StringMatchState::MatchingSecond {
+                    first,
+                    second: Some(_),
+                }) => {
+                    parse_state = Some(StringMatchState::MatchingSecond {
+                        first,
+                        second: None,
+                    })
+                }
+                None => {
+                    break;
+                }
+                Some(_) => {}
+            },
+        }
+    }
+
+    match parse_state {
+        Some(parse_state) => match parse_state {
+            StringMatchState::MatchingFirst(_) => Err(StrLiteralParseError::NoStrFound),
+            StringMatchState::MatchingSecond {
+                first,
+                second: None,
+            } => Err(StrLiteralParseError::StrHasNoEnd {
+                start: first.position,
+            }),
+            StringMatchState::MatchingSecond {
+                first,
+                second: Some(second),
+            } if second.length != first.length => Err(StrLiteralParseError::StrHasNoEnd {
+                start: first.position,
+            }),
+            StringMatchState::MatchingSecond {
+                first,
+                second: Some(second),
+            } => Ok(first.position..(second.position + second.length)),
+        },
+        None => Err(StrLiteralParseError::NoStrFound),
+    }
+}
+
+#[cfg(test)]
+mod template_parse_tests {
+    use crate::TemplateParseError;
+
+    use super::{parse_template, ParseResult};
+
+    #[test]
+    fn parse_html_template() {
+        let to_parse = "<h1>{let x = 15;}{x}</h1>";
+        let result = parse_template(to_parse);
+        assert_eq!(
+            result,
+            Ok(ParseResult {
+                code_block_fragment_ranges: vec![5..16, 18..19],
+                template_fragment_ranges: vec![0..4, 17..17, 20..25],
+            })
+        )
+    }
+
+    #[test]
+    #[allow(clippy::single_range_in_vec_init)]
+    fn parse_static_html_template() {
+        let to_parse = "<h1>static</h1>";
+        let result = parse_template(to_parse);
+        assert_eq!(
+            result,
+            Ok(ParseResult {
+                code_block_fragment_ranges: vec![],
+                template_fragment_ranges: vec![0..15],
+            })
+        )
+    }
+
+    #[test]
+    #[allow(clippy::single_range_in_vec_init)]
+    fn parse_empty_block_followed_by_else_text() {
+        let to_parse = "<p>{} else|</p>";
+        let result = parse_template(to_parse);
+        assert_eq!(
+            result,
+            Ok(ParseResult {
+                code_block_fragment_ranges: vec![4..4],
+                template_fragment_ranges: vec![0..3, 5..15],
+            })
+        )
+    }
+
+    #[test]
+    fn parse_html_template_with_multibyte_characters() {
+        let to_parse = "<h1>ü{let x = \"ä\";}{x}</h1>";
+        let result = parse_template(to_parse);
+        assert_eq!(
+            result,
+            Ok(ParseResult {
+                code_block_fragment_ranges: vec![7..20, 22..23],
+                template_fragment_ranges: vec![0..6, 21..21, 24..29],
+            })
+        )
+    }
+
+    #[test]
+    fn parse_broken_html_template_unclosed_delimiter() {
+        let to_parse = "<h1>{let x = {15;}{x}</h1>";
+        let result = parse_template(to_parse);
+        assert_eq!(
+            result,
+            Err(TemplateParseError::CodeBlockHasNoEnd { position: 4 })
+        )
+    }
+
+    #[test]
+    fn parse_adjacent_blocks() {
+        let to_parse = "{self.a}{self.b}{self.c}";
+        let result = parse_template(to_parse);
+        assert_eq!(
+            result,
+            Ok(ParseResult {
+                code_block_fragment_ranges: vec![1..7, 9..15, 17..23],
+                template_fragment_ranges: vec![0..0, 8..8, 16..16, 24..24],
+            })
+        )
+    }
+
+    #[test]
+    fn parse_html_template_with_scope() {
+        let to_parse = "{ if let Some(x) = &self.opt { }<p>{x}</p>{ } }";
+        let result = parse_template(to_parse);
+        assert_eq!(
+            result,
+            Ok(ParseResult {
+                code_block_fragment_ranges: vec![1..31, 36..37, 43..46],
+                template_fragment_ranges: vec![0..0, 32..35, 38..42, 47..47],
+            })
+        )
+    }
+
+    #[test]
+    fn parse_html_template_with_comment() {
+        let to_parse = "{# a { comment \" #}<p>{x}</p>";
+        let result = parse_template(to_parse);
+        assert_eq!(
+            result,
+            Ok(ParseResult {
+                code_block_fragment_ranges: vec![1..18, 23..24],
+                template_fragment_ranges: vec![0..0, 19..22, 25..29],
+            })
+        )
+    }
+
+    #[test]
+    fn parse_html_template_with_block_comment() {
+        let to_parse = "{% comment %}{ x }{# y #}{% endcomment %}<p>{x}</p>";
+        let result = parse_template(to_parse);
+        assert_eq!(
+            result,
+            Ok(ParseResult {
+                code_block_fragment_ranges: vec![1..40, 45..46],
+                template_fragment_ranges: vec![0..0, 41..44, 47..51],
+            })
+        )
+    }
+
+    #[test]
+    fn parse_block_comment_with_unbalanced_content() {
+        let to_parse = "<p>{ % comment % }{ if x { \"{%endcomment%}</p>{x}";
+        let result = parse_template(to_parse);
+        assert_eq!(
+            result,
+            Ok(ParseResult {
+                code_block_fragment_ranges: vec![4..41, 47..48],
+                template_fragment_ranges: vec![0..3, 42..46, 49..49],
+            })
+        )
+    }
+
+    #[test]
+    fn parse_broken_html_template_unclosed_block_comment() {
+        let to_parse = "<p>{% comment %}{ x }</p>";
+        let result = parse_template(to_parse);
+        assert_eq!(
+            result,
+            Err(TemplateParseError::CodeBlockHasNoEnd { position: 3 })
+        )
+    }
+
+    #[test]
+    fn parse_broken_html_template_after_comment() {
+        let to_parse = "{# comment #}<p>{ self.x </p>";
+        let result = parse_template(to_parse);
+        assert_eq!(
+            result,
+            Err(TemplateParseError::CodeBlockHasNoEnd { position: 16 })
+        )
+    }
+
+    #[test]
+    fn parse_broken_html_template_unclosed_comment() {
+        let to_parse = "<p>{# comment </p>";
+        let result = parse_template(to_parse);
+        assert_eq!(
+            result,
+            Err(TemplateParseError::CodeBlockHasNoEnd { position: 3 })
+        )
+    }
+
+    #[test]
+    fn parse_broken_html_template_unclosed_delimiter_2() {
+        let to_parse = r#"<h1>{let x = "15;}{x}</h1>"#;
+        let result = parse_template(to_parse);
+        assert_eq!(
+            result,
+            Err(TemplateParseError::StrHasNoEnd { position: 13 })
+        )
+    }
+}
+
+#[cfg(test)]
+mod fragment_tests {
+    use super::{parse_fragments, Fragment};
+
+    #[test]
+    fn fragments_of_mixed_template() {
+        let to_parse = "{# comment #}<h1>{ self.title }</h1>{ let x = 1; }{x:?}<p>end</p>";
+        let result = parse_fragments(to_parse);
+        assert_eq!(
+            result,
+            Ok(vec![
+                Fragment::Static("<h1>"),
+                Fragment::Dynamic("self.title"),
+                Fragment::Static("</h1>"),
+                Fragment::Dynamic("let x = 1;"),
+                Fragment::Dynamic("x:?"),
+                Fragment::Static("<p>end</p>"),
+            ])
+        )
+    }
+
+    #[test]
+    fn fragments_of_static_template() {
+        let to_parse = "<h1>static</h1>";
+        let result = parse_fragments(to_parse);
+        assert_eq!(result, Ok(vec![Fragment::Static("<h1>static</h1>")]))
+    }
+}
+
+#[cfg(test)]
+mod code_block_parse_tests {
+    use super::{parse_code_block, CodeBlockParseError};
+
+    #[test]
+    fn parse_block() {
+        let to_parse = "{let x = 15;} <br/>";
+        let result = parse_code_block(to_parse);
+        assert_eq!(result, Ok(12))
+    }
+
+    #[test]
+    fn parse_block_without_end() {
+        let to_parse = "{let x = 15; <br/>";
+        let result = parse_code_block(to_parse);
+        assert_eq!(result, Err(CodeBlockParseError::BlockHasNoEnd))
+    }
+
+    #[test]
+    fn parse_block_without_content() {
+        let to_parse = "{}<br/>";
+        let result = parse_code_block(to_parse);
+        assert_eq!(result, Ok(1))
+    }
+
+    #[test]
+    fn parse_escaped_block() {
+        let to_parse = "{{ <br/>";
+        let result = parse_code_block(to_parse);
+        assert_eq!(result, Err(CodeBlockParseError::Escaped))
+    }
+
+    #[test]
+    fn parse_block_with_str_literal() {
+        let to_parse = r#"{let x = "my str";} <br/>"#;
+        let result = parse_code_block(to_parse);
+        assert_eq!(result, Ok(18))
+    }
+
+    #[test]
+    fn parse_block_with_r_str_literal() {
+        let to_parse = r##"{let x = r#"my "str"#;} <br/>"##;
+        let result = parse_code_block(to_parse);
+        assert_eq!(result, Ok(22))
+    }
+
+    #[test]
+    fn parse_block_with_multiple_str_literal() {
+        let to_parse = r##"{let x = r#"my "str"#; let y = "second str"; } <br/>"##;
+        let result = parse_code_block(to_parse);
+        assert_eq!(result, Ok(45))
+    }
+
+    #[test]
+    fn parse_block_with_format_expression() {
+        let to_parse = r##"{let x = r#"my "str"#; x:? } <br/>"##;
+        let result = parse_code_block(to_parse);
+        assert_eq!(result, Ok(27))
+    }
+
+    #[test]
+    fn parse_block_with_two_str() {
+        let to_parse = r##"{"1""2"}"##;
+        let result = parse_code_block(to_parse);
+        assert_eq!(result, Ok(7))
+    }
+
+    #[test]
+    fn parse_comment() {
+        let to_parse = "{# { \"unbalanced #}<br/>";
+        let result = parse_code_block(to_parse);
+        assert_eq!(result, Ok(18))
+    }
+
+    #[test]
+    fn parse_if_let_scope_start() {
+        let to_parse = "{ if let Some(x) = &self.opt { }<p>{x}</p>{ } }";
+        let result = parse_code_block(to_parse);
+        assert_eq!(result, Ok(31))
+    }
+
+    #[test]
+    fn parse_while_let_scope_start() {
+        let to_parse = "{ while let Some(x) = iter.next() { }<p>{x}</p>{ } }";
+        let result = parse_code_block(to_parse);
+        assert_eq!(result, Ok(36))
+    }
+
+    #[test]
+    fn parse_scope_with_struct_pattern() {
+        let to_parse = "{ if let Point { x, y: 0 } = self.point { }{x}{ } }";
+        let result = parse_code_block(to_parse);
+        assert_eq!(result, Ok(42))
+    }
+
+    #[test]
+    fn parse_scope_end() {
+        let to_parse = "{ } }</p>";
+        let result = parse_code_block(to_parse);
+        assert_eq!(result, Ok(4))
+    }
+
+    #[test]
+    fn parse_scope_continuation() {
+        let to_parse = "{ } else if let Some(y) = &self.y { }{y}{ } }";
+        let result = parse_code_block(to_parse);
+        assert_eq!(result, Ok(36))
+    }
+
+    #[test]
+    fn parse_empty_block() {
+        let to_parse = "{ }</p>";
+        let result = parse_code_block(to_parse);
+        assert_eq!(result, Ok(2))
+    }
+
+    #[test]
+    fn parse_block_with_return() {
+        let to_parse = "{ return x; } <br/>";
+        let result = parse_code_block(to_parse);
+        assert_eq!(result, Ok(12))
+    }
+
+    #[test]
+    fn parse_block_with_ref_pattern() {
+        let to_parse = r#"{ let ref r = "}"; r } <br/>"#;
+        let result = parse_code_block(to_parse);
+        assert_eq!(result, Ok(21))
+    }
+
+    #[test]
+    fn parse_block_with_raw_identifier() {
+        let to_parse = r#"{ let r#type = "}"; r#type } <br/>"#;
+        let result = parse_code_block(to_parse);
+        assert_eq!(result, Ok(27))
+    }
+
+    #[test]
+    fn parse_block_with_raw_str_literal() {
+        let to_parse = r#"{ r"real } raw" } <br/>"#;
+        let result = parse_code_block(to_parse);
+        assert_eq!(result, Ok(16))
+    }
+
+    #[test]
+    fn parse_block_with_raw_str_literal_at_end() {
+        let to_parse = r#"{r"}"}<br/>"#;
+        let result = parse_code_block(to_parse);
+        assert_eq!(result, Ok(5))
+    }
+
+    #[test]
+    fn parse_multi_line_block_with_raw_str_literal() {
+        let to_parse =
+            "{\n    let a = 1;\n    let text = r#\"first }\n  \"second\" {\n\"#;\n} <br/>";
+        let result = parse_code_block(to_parse);
+        assert_eq!(result, Ok(60))
+    }
+
+    #[test]
+    fn parse_block_with_escaped_quote_in_str_literal() {
+        let to_parse = r#"{ let s = "a\"}"; } <br/>"#;
+        let result = parse_code_block(to_parse);
+        assert_eq!(result, Ok(18))
+    }
+
+    #[test]
+    fn parse_block_with_escaped_backslash_in_str_literal() {
+        let to_parse = r#"{ let s = "\\"; } <br/>"#;
+        let result = parse_code_block(to_parse);
+        assert_eq!(result, Ok(16))
+    }
+
+    #[test]
+    fn parse_complete_if_expression() {
+        let to_parse = r#"{ if self.x { "a" } else { "b" } }"#;
+        let result = parse_code_block(to_parse);
+        assert_eq!(result, Ok(33))
+    }
+}
+
+#[cfg(test)]
+mod delimiter_balance_tests {
+    use super::find_unbalanced_delimiter;
+
+    #[test]
+    fn find_no_unbalanced_delimiter() {
+        let to_parse = " self.items.get(0).map(|x| x[1]) ";
+        let result = find_unbalanced_delimiter(to_parse);
+        assert_eq!(result, None)
+    }
+
+    #[test]
+    fn find_unclosed_paren() {
+        let to_parse = " foo( ";
+        let result = find_unbalanced_delimiter(to_parse);
+        assert_eq!(result, Some(4))
+    }
+
+    #[test]
+    fn find_mismatched_bracket() {
+        let to_parse = " self.items[0) ";
+        let result = find_unbalanced_delimiter(to_parse);
+        assert_eq!(result, Some(13))
+    }
+
+    #[test]
+    fn ignore_delimiters_in_literals() {
+        let to_parse = r#" foo("(", ')', r"]", '\'') "#;
+        let result = find_unbalanced_delimiter(to_parse);
+        assert_eq!(result, None)
+    }
+
+    #[test]
+    fn ignore_lifetimes() {
+        let to_parse = " let x: &'static str = bar(1); ";
+        let result = find_unbalanced_delimiter(to_parse);
+        assert_eq!(result, None)
+    }
+}
+
+#[cfg(test)]
+mod str_parse_tests {
+    use super::{parse_str_literal, StrLiteralParseError};
+
+    #[test]
+    fn parse_str_lit() {
+        let to_parse = r###""some " text" rest"###;
+        let result = parse_str_literal(to_parse);
+        assert_eq!(result, Ok(0..6))
+    }
+
+    #[test]
+    fn parse_r_str_lit() {
+        let to_parse = r###"r##"some"# "## text"## rest"###;
+        let result = parse_str_literal(to_parse);
+        assert_eq!(result, Ok(0..13))
+    }
+
+    #[test]
+    fn parse_no_str_lit_at_start() {
+        let to_parse = r###"start "some " text" rest"###;
+        let result = parse_str_literal(to_parse);
+        assert_eq!(result, Err(StrLiteralParseError::NoStrFound))
+    }
+
+    #[test]
+    fn parse_no_r_str_lit_at_start() {
+        let to_parse = r###"start r##"some"# "## text"##"###;
+        let result = parse_str_literal(to_parse);
+        assert_eq!(result, Err(StrLiteralParseError::NoStrFound))
+    }
+
+    #[test]
+    fn parse_no_r_str_lit_end() {
+        let to_parse = r###"r##"some"# text "###;
+        let result = parse_str_literal(to_parse);
+        assert_eq!(result, Err(StrLiteralParseError::StrHasNoEnd { start: 0 }))
+    }
+
+    #[test]
+    fn parse_str_lit_with_escaped_quote() {
+        let to_parse = r#""a\"b" rest"#;
+        let result = parse_str_literal(to_parse);
+        assert_eq!(result, Ok(0..5))
+    }
+
+    #[test]
+    fn parse_str_lit_with_escaped_backslash() {
+        let to_parse = r#""\\" rest"#;
+        let result = parse_str_literal(to_parse);
+        assert_eq!(result, Ok(0..3))
+    }
+
+    #[test]
+    fn parse_r_str_lit_with_backslash() {
+        let to_parse = r#"r"\" rest"#;
+        let result = parse_str_literal(to_parse);
+        assert_eq!(result, Ok(0..3))
+    }
+
+    #[test]
+    fn parse_no_str_lit_end() {
+        let to_parse = r###""some text "###;
+        let result = parse_str_literal(to_parse);
+        assert_eq!(result, Err(StrLiteralParseError::StrHasNoEnd { start: 0 }))
+    }
+}
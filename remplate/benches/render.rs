@@ -0,0 +1,115 @@
+use std::fmt::Write;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use remplate::Remplate;
+
+const LOREM: &str = "Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod tempor incididunt ut labore et dolore magna aliqua.";
+const UT_ENIM: &str = "Ut enim ad minim veniam, quis nostrud exercitation ullamco laboris nisi ut aliquip ex ea commodo consequat.";
+
+#[derive(Remplate)]
+#[remplate(path = "benches/templates/static.html")]
+struct StaticPage;
+
+#[derive(Remplate)]
+#[remplate(path = "benches/templates/interpolations.html")]
+struct Profile {
+    title: &'static str,
+    name: &'static str,
+    age: u32,
+    city: &'static str,
+    country: &'static str,
+    email: &'static str,
+    phone: &'static str,
+    score: f64,
+    rank: usize,
+}
+
+#[derive(Remplate)]
+#[remplate(path = "benches/templates/loop.html")]
+struct ItemList {
+    items: Vec<(String, usize)>,
+}
+
+fn static_template(c: &mut Criterion) {
+    let mut group = c.benchmark_group("static");
+
+    group.bench_function("remplate", |b| b.iter(|| StaticPage.render().unwrap()));
+    group.bench_function("format", |b| {
+        b.iter(|| {
+            format!(
+                "<!DOCTYPE html>\n<html>\n<head>\n    <title>Static page</title>\n</head>\n<body>\n    <h1>Static page</h1>\n    <p>{}</p>\n    <p>{}</p>\n</body>\n</html>\n",
+                LOREM, UT_ENIM
+            )
+        })
+    });
+
+    group.finish();
+}
+
+fn interpolation_template(c: &mut Criterion) {
+    let profile = Profile {
+        title: "Profile",
+        name: "Tom",
+        age: 42,
+        city: "Berlin",
+        country: "Germany",
+        email: "tom@example.com",
+        phone: "+49 30 123456",
+        score: 97.125,
+        rank: 3,
+    };
+
+    let mut group = c.benchmark_group("interpolations");
+
+    group.bench_function("remplate", |b| b.iter(|| profile.render().unwrap()));
+    group.bench_function("format", |b| {
+        b.iter(|| {
+            format!(
+                "<h1>{}</h1>\n<p>{} ({}) lives in {}, {}.</p>\n<p>Email: {}, phone: {}</p>\n<p>Score: {:.2}, rank: {}</p>\n",
+                profile.title,
+                profile.name,
+                profile.age,
+                profile.city,
+                profile.country,
+                profile.email,
+                profile.phone,
+                profile.score,
+                profile.rank
+            )
+        })
+    });
+
+    group.finish();
+}
+
+fn loop_template(c: &mut Criterion) {
+    let list = ItemList {
+        items: (0..100)
+            .map(|index| (format!("item {}", index), index * 7))
+            .collect(),
+    };
+
+    let mut group = c.benchmark_group("loop");
+
+    group.bench_function("remplate", |b| b.iter(|| list.render().unwrap()));
+    group.bench_function("write", |b| {
+        b.iter(|| {
+            let mut rendered = String::from("<ul>\n");
+            for item in &list.items {
+                write!(rendered, "\n    <li>{}: {}</li>\n", item.0, item.1).unwrap();
+            }
+            rendered.push_str("\n</ul>\n");
+            rendered
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    static_template,
+    interpolation_template,
+    loop_template
+);
+criterion_main!(benches);
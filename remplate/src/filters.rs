@@ -0,0 +1,941 @@
+use core::fmt::{self, Display, Write};
+
+const DANGEROUS_URL_SCHEMES: [&str; 3] = ["javascript:", "vbscript:", "data:"];
+
+pub struct Attr<T>(T);
+
+pub fn attr<T: Display>(value: T) -> Attr<T> {
+    Attr(value)
+}
+
+impl<T: Display> Display for Attr<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = display_string(&self.0)?;
+
+        if has_dangerous_url_scheme(&value) {
+            return Ok(());
+        }
+
+        write_padded(f, &value, write_escaped_html)
+    }
+}
+
+pub struct UnquotedAttr<T>(T);
+
+pub fn unquoted_attr<T: Display>(value: T) -> UnquotedAttr<T> {
+    UnquotedAttr(value)
+}
+
+impl<T: Display> Display for UnquotedAttr<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = display_string(&self.0)?;
+
+        if has_dangerous_url_scheme(&value) {
+            return Ok(());
+        }
+
+        write_padded(f, &value, write_escaped_unquoted_attribute)
+    }
+}
+
+pub struct HtmlIf<T> {
+    value: T,
+    escape: bool,
+}
+
+pub fn html_if<T: Display>(value: T, escape: bool) -> HtmlIf<T> {
+    HtmlIf { value, escape }
+}
+
+impl<T: Display> Display for HtmlIf<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.escape {
+            true => html(&self.value).fmt(f),
+            false => self.value.fmt(f),
+        }
+    }
+}
+
+macro_rules! escape_filter {
+    ($($filter_type:ident $filter_name:ident $write_escaped:ident),* $(,)?) => {
+        $(
+            pub struct $filter_type<T>(T);
+
+            pub fn $filter_name<T: Display>(value: T) -> $filter_type<T> {
+                $filter_type(value)
+            }
+
+            impl<T: Display> Display for $filter_type<T> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    if !is_padded(f) {
+                        return write!(EscapingWriter::new(f, $write_escaped), "{}", self.0);
+                    }
+
+                    let mut escaped = String::new();
+                    write!(EscapingWriter::new(&mut escaped, $write_escaped), "{}", self.0)?;
+
+                    f.pad(&escaped)
+                }
+            }
+        )*
+    };
+}
+
+escape_filter!(
+    Html html write_escaped_html,
+    Js js write_escaped_js,
+    Xml xml write_escaped_xml,
+    Json json write_escaped_json,
+    Sql sql write_escaped_sql,
+    Css css write_escaped_css,
+);
+
+type WriteEscaped = fn(&mut dyn Write, &str) -> fmt::Result;
+
+struct EscapingWriter<'a> {
+    writer: &'a mut dyn Write,
+    write_escaped: WriteEscaped,
+}
+
+impl<'a> EscapingWriter<'a> {
+    fn new(writer: &'a mut dyn Write, write_escaped: WriteEscaped) -> Self {
+        Self {
+            writer,
+            write_escaped,
+        }
+    }
+}
+
+impl Write for EscapingWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        (self.write_escaped)(self.writer, s)
+    }
+}
+
+fn is_padded(f: &fmt::Formatter<'_>) -> bool {
+    f.width().is_some() || f.precision().is_some()
+}
+
+fn write_padded(
+    f: &mut fmt::Formatter<'_>,
+    value: &str,
+    write_escaped: WriteEscaped,
+) -> fmt::Result {
+    if !is_padded(f) {
+        return write_escaped(f, value);
+    }
+
+    let mut escaped = String::with_capacity(value.len());
+    write_escaped(&mut escaped, value)?;
+
+    f.pad(&escaped)
+}
+
+pub struct Csv<T>(T);
+
+pub fn csv<T: Display>(value: T) -> Csv<T> {
+    Csv(value)
+}
+
+impl<T: Display> Display for Csv<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = display_string(&self.0)?;
+
+        write_padded(f, &value, write_quoted_csv)
+    }
+}
+
+fn display_string<T: Display + ?Sized>(value: &T) -> Result<String, fmt::Error> {
+    let mut string = String::new();
+    write!(string, "{}", value)?;
+
+    Ok(string)
+}
+
+pub fn raw<T: Display>(value: T) -> T {
+    value
+}
+
+pub fn path<T: AsRef<::std::path::Path> + ?Sized>(value: &T) -> ::std::path::Display<'_> {
+    value.as_ref().display()
+}
+
+pub struct Join<'a, T: ?Sized> {
+    values: &'a T,
+    separator: &'a str,
+}
+
+pub fn join<'a, T>(values: &'a T, separator: &'a str) -> Join<'a, T>
+where
+    T: ?Sized,
+    &'a T: IntoIterator,
+    <&'a T as IntoIterator>::Item: Display,
+{
+    Join { values, separator }
+}
+
+impl<'a, T> Display for Join<'a, T>
+where
+    T: ?Sized,
+    &'a T: IntoIterator,
+    <&'a T as IntoIterator>::Item: Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, value) in self.values.into_iter().enumerate() {
+            if index > 0 {
+                f.write_str(self.separator)?;
+            }
+
+            value.fmt(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+macro_rules! radix_filter {
+    ($($filter_type:ident, $filter_name:ident, $format_trait:ident);*) => {
+        $(
+            pub struct $filter_type<T>(T);
+
+            pub fn $filter_name<T: fmt::$format_trait>(value: T) -> $filter_type<T> {
+                $filter_type(value)
+            }
+
+            impl<T: fmt::$format_trait> Display for $filter_type<T> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::$format_trait::fmt(&self.0, f)
+                }
+            }
+        )*
+    };
+}
+
+radix_filter!(Hex, hex, LowerHex; Bin, bin, Binary; Oct, oct, Octal);
+
+pub struct Group<'a, T: ?Sized> {
+    value: &'a T,
+    separator: &'a str,
+}
+
+pub fn group<'a, T: Display + ?Sized>(value: &'a T, separator: &'a str) -> Group<'a, T> {
+    Group { value, separator }
+}
+
+impl<T: Display + ?Sized> Display for Group<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = display_string(&self.value)?;
+        let (sign, unsigned_value) = match value.strip_prefix('-') {
+            Some(unsigned_value) => ("-", unsigned_value),
+            None => ("", value.as_str()),
+        };
+        let integer_length = unsigned_value
+            .find(|character: char| !character.is_ascii_digit())
+            .unwrap_or(unsigned_value.len());
+        let (integer_part, rest) = unsigned_value.split_at(integer_length);
+
+        let mut grouped = String::with_capacity(value.len() * 2);
+        grouped.push_str(sign);
+        for (index, digit) in integer_part.char_indices() {
+            if index > 0 && (integer_part.len() - index) % 3 == 0 {
+                grouped.push_str(self.separator);
+            }
+            grouped.push(digit);
+        }
+        grouped.push_str(rest);
+
+        f.pad(&grouped)
+    }
+}
+
+pub struct Truncate<T> {
+    value: T,
+    length: usize,
+}
+
+pub fn truncate<T: Display>(value: T, length: usize) -> Truncate<T> {
+    Truncate { value, length }
+}
+
+impl<T: Display> Display for Truncate<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = display_string(&self.value)?;
+
+        match value.char_indices().nth(self.length) {
+            Some((position, _)) => f.pad(&format!("{}\u{2026}", &value[..position])),
+            None => f.pad(&value),
+        }
+    }
+}
+
+pub struct OkOrErr<'a, T, E>(&'a Result<T, E>);
+
+pub fn ok_or_err<T: Display, E: Display>(value: &Result<T, E>) -> OkOrErr<'_, T, E> {
+    OkOrErr(value)
+}
+
+impl<T: Display, E: Display> Display for OkOrErr<'_, T, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Ok(value) => value.fmt(f),
+            Err(error) => error.fmt(f),
+        }
+    }
+}
+
+pub trait AttributeValue {
+    fn write_attribute(&self, name: &str, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+}
+
+impl AttributeValue for bool {
+    fn write_attribute(&self, name: &str, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            true => f.write_fmt(format_args!(" {}", name)),
+            false => Ok(()),
+        }
+    }
+}
+
+impl<T: AttributeValue> AttributeValue for Option<T> {
+    fn write_attribute(&self, name: &str, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Some(value) => value.write_attribute(name, f),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<T: AttributeValue + ?Sized> AttributeValue for &T {
+    fn write_attribute(&self, name: &str, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (**self).write_attribute(name, f)
+    }
+}
+
+macro_rules! impl_display_attribute_value {
+    ($($value_type:ty),*) => {
+        $(
+            impl AttributeValue for $value_type {
+                fn write_attribute(&self, name: &str, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    f.write_fmt(format_args!(" {}=\"", name))?;
+                    write_escaped_html(f, &self.to_string())?;
+                    f.write_str("\"")
+                }
+            }
+        )*
+    };
+}
+
+impl_display_attribute_value!(
+    str, String, char, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64
+);
+
+pub struct Attribute<'a, T> {
+    name: &'a str,
+    value: T,
+}
+
+pub fn attribute<T: AttributeValue>(name: &str, value: T) -> Attribute<'_, T> {
+    Attribute { name, value }
+}
+
+impl<T: AttributeValue> Display for Attribute<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.value.write_attribute(self.name, f)
+    }
+}
+
+#[cfg(feature = "serde_json")]
+pub struct JsonPath<'a> {
+    value: Option<&'a serde_json::Value>,
+}
+
+#[cfg(feature = "serde_json")]
+pub fn jsonpath<'a>(value: &'a serde_json::Value, path: &str) -> JsonPath<'a> {
+    let value = path
+        .split('.')
+        .filter(|key| !key.is_empty())
+        .try_fold(value, |value, key| match value {
+            serde_json::Value::Array(values) => key
+                .parse::<usize>()
+                .ok()
+                .and_then(|index| values.get(index)),
+            value => value.get(key),
+        });
+
+    JsonPath { value }
+}
+
+#[cfg(feature = "serde_json")]
+impl Display for JsonPath<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.value {
+            None | Some(serde_json::Value::Null) => Ok(()),
+            Some(serde_json::Value::String(value)) => f.pad(value),
+            Some(value) => value.fmt(f),
+        }
+    }
+}
+
+#[cfg(any(feature = "chrono", feature = "time"))]
+pub trait DateValue {
+    fn write_date(&self, format: &str, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+}
+
+#[cfg(any(feature = "chrono", feature = "time"))]
+impl<T: DateValue + ?Sized> DateValue for &T {
+    fn write_date(&self, format: &str, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (**self).write_date(format, f)
+    }
+}
+
+#[cfg(feature = "chrono")]
+macro_rules! impl_chrono_date_value {
+    ($($value_type:ty),*) => {
+        $(
+            impl DateValue for $value_type {
+                fn write_date(&self, format: &str, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    self.format(format).fmt(f)
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "chrono")]
+impl_chrono_date_value!(chrono::NaiveDate, chrono::NaiveDateTime, chrono::NaiveTime);
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> DateValue for chrono::DateTime<Tz>
+where
+    Tz::Offset: Display,
+{
+    fn write_date(&self, format: &str, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.format(format).fmt(f)
+    }
+}
+
+#[cfg(feature = "time")]
+macro_rules! impl_time_date_value {
+    ($($value_type:ty),*) => {
+        $(
+            impl DateValue for $value_type {
+                fn write_date(&self, format: &str, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    let description = time_format_description(format)?;
+                    let items = time::format_description::parse_borrowed::<1>(&description)
+                        .map_err(|_| fmt::Error)?;
+
+                    f.pad(&self.format(&items).map_err(|_| fmt::Error)?)
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "time")]
+impl_time_date_value!(
+    time::Date,
+    time::Time,
+    time::PrimitiveDateTime,
+    time::OffsetDateTime
+);
+
+#[cfg(feature = "time")]
+fn time_format_description(format: &str) -> Result<String, fmt::Error> {
+    let mut description = String::with_capacity(format.len() * 2);
+    let mut characters = format.chars();
+
+    while let Some(character) = characters.next() {
+        match character {
+            '%' => description.push_str(match characters.next().ok_or(fmt::Error)? {
+                'Y' => "[year]",
+                'y' => "[year repr:last_two]",
+                'm' => "[month]",
+                'b' => "[month repr:short]",
+                'B' => "[month repr:long]",
+                'd' => "[day]",
+                'e' => "[day padding:space]",
+                'j' => "[ordinal]",
+                'a' => "[weekday repr:short]",
+                'A' => "[weekday]",
+                'H' => "[hour]",
+                'I' => "[hour repr:12]",
+                'p' => "[period]",
+                'M' => "[minute]",
+                'S' => "[second]",
+                '%' => "%",
+                _ => return Err(fmt::Error),
+            }),
+            '[' => description.push_str("[["),
+            character => description.push(character),
+        }
+    }
+
+    Ok(description)
+}
+
+#[cfg(any(feature = "chrono", feature = "time"))]
+pub struct Date<'a, T: ?Sized> {
+    value: &'a T,
+    format: &'a str,
+}
+
+#[cfg(any(feature = "chrono", feature = "time"))]
+pub fn date<'a, T: DateValue + ?Sized>(value: &'a T, format: &'a str) -> Date<'a, T> {
+    Date { value, format }
+}
+
+#[cfg(any(feature = "chrono", feature = "time"))]
+impl<T: DateValue + ?Sized> Display for Date<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.value.write_date(self.format, f)
+    }
+}
+
+fn has_dangerous_url_scheme(value: &str) -> bool {
+    let normalized_value: String = value
+        .chars()
+        .filter(|character| !character.is_ascii_whitespace() && !character.is_control())
+        .take(
+            DANGEROUS_URL_SCHEMES
+                .iter()
+                .map(|scheme| scheme.len())
+                .max()
+                .unwrap_or(0),
+        )
+        .map(|character| character.to_ascii_lowercase())
+        .collect();
+
+    DANGEROUS_URL_SCHEMES
+        .iter()
+        .any(|scheme| normalized_value.starts_with(scheme))
+}
+
+fn write_escaped_html(writer: &mut dyn Write, value: &str) -> fmt::Result {
+    let mut last_position = 0;
+
+    for (position, character) in value.char_indices() {
+        let escaped = match character {
+            '&' => "&amp;",
+            '<' => "&lt;",
+            '>' => "&gt;",
+            '"' => "&quot;",
+            '\'' => "&#x27;",
+            _ => continue,
+        };
+
+        writer.write_str(&value[last_position..position])?;
+        writer.write_str(escaped)?;
+        last_position = position + character.len_utf8();
+    }
+
+    writer.write_str(&value[last_position..])
+}
+
+fn write_escaped_unquoted_attribute(writer: &mut dyn Write, value: &str) -> fmt::Result {
+    for character in value.chars() {
+        match character.is_alphanumeric() {
+            true => writer.write_char(character)?,
+            false => writer.write_fmt(format_args!("&#x{:X};", character as u32))?,
+        }
+    }
+
+    Ok(())
+}
+
+fn write_escaped_js(writer: &mut dyn Write, value: &str) -> fmt::Result {
+    for character in value.chars() {
+        match character {
+            '\\' => writer.write_str("\\\\")?,
+            '"' => writer.write_str("\\\"")?,
+            '\'' => writer.write_str("\\'")?,
+            '\n' => writer.write_str("\\n")?,
+            '\r' => writer.write_str("\\r")?,
+            '<' | '>' | '&' | '\u{2028}' | '\u{2029}' => {
+                writer.write_fmt(format_args!("\\u{:04X}", character as u32))?
+            }
+            character if character.is_control() => {
+                writer.write_fmt(format_args!("\\u{:04X}", character as u32))?
+            }
+            character => writer.write_char(character)?,
+        }
+    }
+
+    Ok(())
+}
+
+fn write_escaped_xml(writer: &mut dyn Write, value: &str) -> fmt::Result {
+    for character in value.chars() {
+        match character {
+            '&' => writer.write_str("&amp;")?,
+            '<' => writer.write_str("&lt;")?,
+            '>' => writer.write_str("&gt;")?,
+            '"' => writer.write_str("&quot;")?,
+            '\'' => writer.write_str("&apos;")?,
+            character => writer.write_char(character)?,
+        }
+    }
+
+    Ok(())
+}
+
+fn write_escaped_json(writer: &mut dyn Write, value: &str) -> fmt::Result {
+    for character in value.chars() {
+        match character {
+            '\\' => writer.write_str("\\\\")?,
+            '"' => writer.write_str("\\\"")?,
+            '\n' => writer.write_str("\\n")?,
+            '\r' => writer.write_str("\\r")?,
+            '\t' => writer.write_str("\\t")?,
+            character if character.is_control() => {
+                writer.write_fmt(format_args!("\\u{:04x}", character as u32))?
+            }
+            character => writer.write_char(character)?,
+        }
+    }
+
+    Ok(())
+}
+
+fn write_quoted_csv(writer: &mut dyn Write, value: &str) -> fmt::Result {
+    if !value.contains([',', '"', '\n', '\r']) {
+        return writer.write_str(value);
+    }
+
+    writer.write_char('"')?;
+    for (index, part) in value.split('"').enumerate() {
+        if index > 0 {
+            writer.write_str("\"\"")?;
+        }
+        writer.write_str(part)?;
+    }
+    writer.write_char('"')
+}
+
+fn write_escaped_sql(writer: &mut dyn Write, value: &str) -> fmt::Result {
+    for (index, part) in value.split('\'').enumerate() {
+        if index > 0 {
+            writer.write_str("''")?;
+        }
+        writer.write_str(part)?;
+    }
+
+    Ok(())
+}
+
+fn write_escaped_css(writer: &mut dyn Write, value: &str) -> fmt::Result {
+    for character in value.chars() {
+        match character.is_alphanumeric() {
+            true => writer.write_char(character)?,
+            false => writer.write_fmt(format_args!("\\{:X} ", character as u32))?,
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod escape_tests {
+    use core::fmt::{self, Display, Write};
+
+    use super::{css, csv, html, js, json, sql, xml};
+
+    struct Failing;
+
+    impl Display for Failing {
+        fn fmt(&self, _: &mut fmt::Formatter<'_>) -> fmt::Result {
+            Err(fmt::Error)
+        }
+    }
+
+    #[test]
+    fn escape_html_in_parts() {
+        assert_eq!(
+            html(format_args!("<{}>{}", "b", "&")).to_string(),
+            "&lt;b&gt;&amp;"
+        );
+    }
+
+    #[test]
+    fn pad_escaped_html() {
+        assert_eq!(format!("{:>12}", html("<b>")), "   &lt;b&gt;");
+    }
+
+    #[test]
+    fn propagate_display_error() {
+        let mut escaped = String::new();
+        assert!(write!(escaped, "{}", html(Failing)).is_err());
+    }
+
+    #[test]
+    fn escape_html() {
+        assert_eq!(
+            html("<b>\"Tom\" & 'Jerry'</b>").to_string(),
+            "&lt;b&gt;&quot;Tom&quot; &amp; &#x27;Jerry&#x27;&lt;/b&gt;"
+        );
+    }
+
+    #[test]
+    fn escape_js_string_breakout() {
+        assert_eq!(
+            js("\";</script><script>alert('x')\n").to_string(),
+            "\\\";\\u003C/script\\u003E\\u003Cscript\\u003Ealert(\\'x\\')\\n"
+        );
+    }
+
+    #[test]
+    fn escape_js_line_separators() {
+        assert_eq!(js("a\u{2028}b\u{0}").to_string(), "a\\u2028b\\u0000");
+    }
+
+    #[test]
+    fn escape_xml() {
+        assert_eq!(
+            xml("<a href='x'>\"&\"</a>").to_string(),
+            "&lt;a href=&apos;x&apos;&gt;&quot;&amp;&quot;&lt;/a&gt;"
+        );
+    }
+
+    #[test]
+    fn escape_json_string() {
+        assert_eq!(
+            json("\"quoted\"\\\n\u{1}").to_string(),
+            "\\\"quoted\\\"\\\\\\n\\u0001"
+        );
+    }
+
+    #[test]
+    fn quote_csv_field() {
+        assert_eq!(csv("plain").to_string(), "plain");
+        assert_eq!(csv("a, \"b\"").to_string(), "\"a, \"\"b\"\"\"");
+    }
+
+    #[test]
+    fn escape_css_value() {
+        assert_eq!(css("red").to_string(), "red");
+        assert_eq!(
+            css("red;}</style>").to_string(),
+            "red\\3B \\7D \\3C \\2F style\\3E "
+        );
+    }
+
+    #[test]
+    fn escape_sql_string() {
+        assert_eq!(sql("O'Brien").to_string(), "O''Brien");
+    }
+}
+
+#[cfg(test)]
+mod attr_tests {
+    use super::{attr, unquoted_attr};
+
+    #[test]
+    fn escape_attribute_breakout() {
+        assert_eq!(
+            attr("\"><script>alert(1)</script>").to_string(),
+            "&quot;&gt;&lt;script&gt;alert(1)&lt;/script&gt;"
+        );
+    }
+
+    #[test]
+    fn strip_javascript_url() {
+        assert_eq!(attr("javascript:alert(1)").to_string(), "");
+    }
+
+    #[test]
+    fn strip_obfuscated_javascript_url() {
+        assert_eq!(attr(" JaVa\tScRiPt:alert(1)").to_string(), "");
+    }
+
+    #[test]
+    fn escape_unquoted_attribute_breakout() {
+        assert_eq!(
+            unquoted_attr("x onmouseover=alert(1)").to_string(),
+            "x&#x20;onmouseover&#x3D;alert&#x28;1&#x29;"
+        );
+        assert_eq!(unquoted_attr("javascript:alert(1)").to_string(), "");
+    }
+
+    #[test]
+    fn keep_safe_url() {
+        assert_eq!(
+            attr("https://example.com/?a=1&b=2").to_string(),
+            "https://example.com/?a=1&amp;b=2"
+        );
+    }
+}
+
+#[cfg(test)]
+mod join_tests {
+    use super::join;
+
+    #[test]
+    fn join_empty() {
+        let values: Vec<u32> = vec![];
+        assert_eq!(join(&values, ", ").to_string(), "");
+    }
+
+    #[test]
+    fn join_single() {
+        assert_eq!(join(&["a"], ", ").to_string(), "a");
+    }
+
+    #[test]
+    fn join_multiple() {
+        assert_eq!(join(&vec!["a", "b", "c"], ", ").to_string(), "a, b, c");
+    }
+}
+
+#[cfg(all(test, feature = "serde_json"))]
+mod jsonpath_tests {
+    use super::jsonpath;
+
+    #[test]
+    fn jsonpath_present() {
+        let value = serde_json::json!({ "user": { "name": "Ferris", "ids": [1, 2] } });
+        assert_eq!(jsonpath(&value, "user.name").to_string(), "Ferris");
+        assert_eq!(jsonpath(&value, "user.ids.1").to_string(), "2");
+        assert_eq!(jsonpath(&value, "user.ids").to_string(), "[1,2]");
+    }
+
+    #[test]
+    fn jsonpath_missing() {
+        let value = serde_json::json!({ "user": { "name": "Ferris" } });
+        assert_eq!(jsonpath(&value, "user.email").to_string(), "");
+        assert_eq!(jsonpath(&value, "user.name.first").to_string(), "");
+    }
+}
+
+#[cfg(all(test, feature = "chrono"))]
+mod chrono_date_tests {
+    use super::date;
+
+    #[test]
+    fn date_of_naive_date() {
+        let value = chrono::NaiveDate::from_ymd_opt(2024, 3, 9).unwrap();
+        assert_eq!(date(&value, "%Y-%m-%d").to_string(), "2024-03-09");
+        assert_eq!(date(&value, "%d.%m.%y").to_string(), "09.03.24");
+    }
+}
+
+#[cfg(all(test, feature = "time"))]
+mod time_date_tests {
+    use super::date;
+
+    #[test]
+    fn date_of_time_date() {
+        let value = time::Date::from_calendar_date(2024, time::Month::March, 9).unwrap();
+        assert_eq!(date(&value, "%Y-%m-%d").to_string(), "2024-03-09");
+        assert_eq!(date(&value, "[%d] %B").to_string(), "[09] March");
+    }
+
+    #[test]
+    fn date_with_unknown_specifier() {
+        let value = time::Date::from_calendar_date(2024, time::Month::March, 9).unwrap();
+        let mut rendered = String::new();
+
+        assert!(
+            std::fmt::Write::write_fmt(&mut rendered, format_args!("{}", date(&value, "%Q")))
+                .is_err()
+        );
+    }
+}
+
+#[cfg(test)]
+mod radix_tests {
+    use super::{bin, hex, oct};
+
+    #[test]
+    fn radix_filters() {
+        assert_eq!(hex(255u8).to_string(), "ff");
+        assert_eq!(bin(5u8).to_string(), "101");
+        assert_eq!(oct(8u8).to_string(), "10");
+    }
+
+    #[test]
+    fn radix_filters_with_format_spec() {
+        assert_eq!(format!("{:04}", hex(255u8)), "00ff");
+        assert_eq!(format!("{:#010}", bin(5u8)), "0b00000101");
+    }
+}
+
+#[cfg(test)]
+mod group_tests {
+    use super::group;
+
+    #[test]
+    fn group_integers() {
+        assert_eq!(group(&1234567, "_").to_string(), "1_234_567");
+        assert_eq!(group(&-1000, ",").to_string(), "-1,000");
+        assert_eq!(group(&999, ",").to_string(), "999");
+    }
+
+    #[test]
+    fn group_decimals() {
+        assert_eq!(group(&1234.5, ",").to_string(), "1,234.5");
+        assert_eq!(group(&-0.25, ",").to_string(), "-0.25");
+    }
+}
+
+#[cfg(test)]
+mod path_tests {
+    use std::path::{Path, PathBuf};
+
+    use super::path;
+
+    #[test]
+    fn display_paths() {
+        assert_eq!(path(Path::new("/srv/www")).to_string(), "/srv/www");
+        assert_eq!(
+            path(&PathBuf::from("templates").join("page.html")).to_string(),
+            "templates/page.html"
+        );
+        assert_eq!(path("relative/file.txt").to_string(), "relative/file.txt");
+    }
+}
+
+#[cfg(test)]
+mod attribute_tests {
+    use super::attribute;
+
+    #[test]
+    fn boolean_attribute() {
+        assert_eq!(attribute("disabled", true).to_string(), " disabled");
+        assert_eq!(attribute("disabled", false).to_string(), "");
+    }
+
+    #[test]
+    fn valued_attribute() {
+        assert_eq!(
+            attribute("value", "\"quoted\" & <b>").to_string(),
+            " value=\"&quot;quoted&quot; &amp; &lt;b&gt;\""
+        );
+        assert_eq!(attribute("tabindex", 3).to_string(), " tabindex=\"3\"");
+        assert_eq!(attribute("title", None::<&str>).to_string(), "");
+    }
+}
+
+#[cfg(test)]
+mod truncate_tests {
+    use super::truncate;
+
+    #[test]
+    fn truncate_under_limit() {
+        assert_eq!(truncate("short", 10).to_string(), "short");
+    }
+
+    #[test]
+    fn truncate_at_limit() {
+        assert_eq!(truncate("exact", 5).to_string(), "exact");
+        assert_eq!(truncate("größe", 5).to_string(), "größe");
+    }
+
+    #[test]
+    fn truncate_over_limit() {
+        assert_eq!(truncate("too long", 3).to_string(), "too\u{2026}");
+        assert_eq!(
+            truncate("日本語のテキスト", 3).to_string(),
+            "日本語\u{2026}"
+        );
+        assert_eq!(truncate("ünïcödé", 0).to_string(), "\u{2026}");
+    }
+}
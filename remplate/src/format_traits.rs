@@ -0,0 +1,42 @@
+use core::fmt::{Binary, Debug, Display, LowerExp, LowerHex, Octal, UpperExp, UpperHex};
+
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` doesn't implement `std::fmt::Display` and can not be interpolated",
+    label = "`{Self}` is interpolated here",
+    note = "`Path` and `PathBuf` values can be interpolated with the `path` filter: `{{ self.path | path }}`"
+)]
+pub trait Interpolate: Display {}
+
+impl<T: Display + ?Sized> Interpolate for T {}
+
+pub fn display<T: Interpolate>(value: &T) -> &dyn Display {
+    value
+}
+
+pub fn debug<T: Debug>(value: &T) -> &dyn Debug {
+    value
+}
+
+pub fn lower_hex<T: LowerHex>(value: &T) -> &dyn LowerHex {
+    value
+}
+
+pub fn upper_hex<T: UpperHex>(value: &T) -> &dyn UpperHex {
+    value
+}
+
+pub fn octal<T: Octal>(value: &T) -> &dyn Octal {
+    value
+}
+
+pub fn binary<T: Binary>(value: &T) -> &dyn Binary {
+    value
+}
+
+pub fn lower_exp<T: LowerExp>(value: &T) -> &dyn LowerExp {
+    value
+}
+
+pub fn upper_exp<T: UpperExp>(value: &T) -> &dyn UpperExp {
+    value
+}
@@ -0,0 +1,210 @@
+use core::fmt;
+
+const VOID_ELEMENTS: [&str; 14] = [
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source",
+    "track", "wbr",
+];
+
+const RAW_TEXT_ELEMENTS: [&str; 2] = ["script", "style"];
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum UnbalancedTag {
+    Unclosed {
+        name: String,
+        position: usize,
+    },
+    Unexpected {
+        name: String,
+        position: usize,
+    },
+    Mismatched {
+        expected: String,
+        found: String,
+        position: usize,
+    },
+}
+
+impl fmt::Display for UnbalancedTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UnbalancedTag::Unclosed { name, position } => {
+                write!(f, "unclosed `<{}>` at byte {}", name, position)
+            }
+            UnbalancedTag::Unexpected { name, position } => {
+                write!(f, "unexpected `</{}>` at byte {}", name, position)
+            }
+            UnbalancedTag::Mismatched {
+                expected,
+                found,
+                position,
+            } => write!(
+                f,
+                "expected `</{}>` but found `</{}>` at byte {}",
+                expected, found, position
+            ),
+        }
+    }
+}
+
+struct Tag<'a> {
+    name: &'a str,
+    is_closing: bool,
+    is_self_closing: bool,
+    end: usize,
+}
+
+fn parse_tag(html: &str, start: usize) -> Option<Tag<'_>> {
+    let rest = &html[(start + 1)..];
+    let (is_closing, rest) = match rest.strip_prefix('/') {
+        Some(rest) => (true, rest),
+        None => (false, rest),
+    };
+
+    let name_length = rest
+        .find(|character: char| !(character.is_ascii_alphanumeric() || character == '-'))
+        .unwrap_or(rest.len());
+
+    if name_length == 0 || !rest.starts_with(|character: char| character.is_ascii_alphabetic()) {
+        return None;
+    }
+
+    let mut quote = None;
+    for (index, character) in rest.char_indices().skip(name_length) {
+        match (character, quote) {
+            ('"' | '\'', None) => quote = Some(character),
+            (character, Some(open_quote)) if character == open_quote => quote = None,
+            ('>', None) => {
+                return Some(Tag {
+                    name: &rest[..name_length],
+                    is_closing,
+                    is_self_closing: rest[..index].ends_with('/'),
+                    end: html.len() - rest.len() + index + 1,
+                })
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+fn find_raw_text_end(html: &str, start: usize, name: &str) -> usize {
+    let closing_tag = format!("</{}", name);
+
+    html[start..]
+        .to_ascii_lowercase()
+        .find(&closing_tag)
+        .map(|position| start + position)
+        .unwrap_or(html.len())
+}
+
+pub fn find_unbalanced_tag(html: &str) -> Option<UnbalancedTag> {
+    let mut open_tags: Vec<(String, usize)> = Vec::new();
+    let mut position = 0;
+
+    while let Some(offset) = html[position..].find('<') {
+        let start = position + offset;
+
+        if html[start..].starts_with("<!--") {
+            position = html[start..]
+                .find("-->")
+                .map(|end| start + end + 3)
+                .unwrap_or(html.len());
+            continue;
+        }
+
+        let Some(tag) = parse_tag(html, start) else {
+            position = start + 1;
+            continue;
+        };
+        let name = tag.name.to_ascii_lowercase();
+        position = tag.end;
+
+        if tag.is_closing {
+            match open_tags.pop() {
+                Some((open_name, _)) if open_name == name => {}
+                Some((open_name, _)) => {
+                    return Some(UnbalancedTag::Mismatched {
+                        expected: open_name,
+                        found: name,
+                        position: start,
+                    })
+                }
+                None => {
+                    return Some(UnbalancedTag::Unexpected {
+                        name,
+                        position: start,
+                    })
+                }
+            }
+        } else if !tag.is_self_closing && !VOID_ELEMENTS.contains(&name.as_str()) {
+            if RAW_TEXT_ELEMENTS.contains(&name.as_str()) {
+                position = find_raw_text_end(html, position, &name);
+            }
+
+            open_tags.push((name, start));
+        }
+    }
+
+    open_tags
+        .pop()
+        .map(|(name, position)| UnbalancedTag::Unclosed { name, position })
+}
+
+pub fn assert_balanced_tags(html: &str) {
+    if let Some(unbalanced_tag) = find_unbalanced_tag(html) {
+        panic!("Unbalanced HTML in rendered template: {}", unbalanced_tag);
+    }
+}
+
+#[cfg(test)]
+mod html_check_tests {
+    use super::{find_unbalanced_tag, UnbalancedTag};
+
+    #[test]
+    fn balanced_tags() {
+        let html =
+            r#"<!DOCTYPE html><div class="a>b"><br><img src="x"/><p>Text</p><!-- <p> --></div>"#;
+        assert_eq!(find_unbalanced_tag(html), None);
+    }
+
+    #[test]
+    fn balanced_tags_with_script() {
+        let html = "<script>if (a < b && c > d) { document.write('<p>'); }</script>";
+        assert_eq!(find_unbalanced_tag(html), None);
+    }
+
+    #[test]
+    fn unclosed_tag() {
+        assert_eq!(
+            find_unbalanced_tag("<div><p>Text</p>"),
+            Some(UnbalancedTag::Unclosed {
+                name: "div".to_string(),
+                position: 0,
+            })
+        );
+    }
+
+    #[test]
+    fn mismatched_tag() {
+        assert_eq!(
+            find_unbalanced_tag("<div><p>Text</div>"),
+            Some(UnbalancedTag::Mismatched {
+                expected: "p".to_string(),
+                found: "div".to_string(),
+                position: 12,
+            })
+        );
+    }
+
+    #[test]
+    fn unexpected_closing_tag() {
+        assert_eq!(
+            find_unbalanced_tag("a < b</p>"),
+            Some(UnbalancedTag::Unexpected {
+                name: "p".to_string(),
+                position: 5,
+            })
+        );
+    }
+}
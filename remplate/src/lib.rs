@@ -1,14 +1,398 @@
+pub mod filters;
+pub mod format_traits;
+pub mod html_check;
+
 pub trait Remplate: core::fmt::Display {
     const ESTIMATED_SIZE: usize;
+    const SIZE_HINT: (usize, Option<usize>) = (0, None);
+    const HAS_POSTPROCESS: bool = false;
+
+    fn estimated_size(&self) -> usize {
+        Self::ESTIMATED_SIZE
+    }
 
     fn render(&self) -> Result<String, ::core::fmt::Error> {
+        let capacity = self.estimated_size();
+        debug_assert!(
+            capacity > 0,
+            "estimated size of a template must not be zero"
+        );
+
+        self.render_with_capacity(capacity)
+    }
+
+    fn postprocess(rendered: String) -> String {
+        rendered
+    }
+
+    fn render_with_capacity(&self, capacity: usize) -> Result<String, ::core::fmt::Error> {
         use std::fmt::Write;
 
-        let mut rendered = ::std::string::String::with_capacity(Self::ESTIMATED_SIZE);
+        let mut rendered = ::std::string::String::with_capacity(capacity);
         rendered.write_fmt(format_args!("{}", self))?;
 
-        Ok(rendered)
+        Ok(Self::postprocess(rendered))
+    }
+
+    fn render_arc(&self) -> Result<::std::sync::Arc<str>, ::core::fmt::Error> {
+        self.render().map(::std::sync::Arc::from)
+    }
+
+    fn render_appending(&self, buf: &mut String) -> Result<usize, ::core::fmt::Error> {
+        let initial_length = buf.len();
+        buf.reserve(self.estimated_size());
+        write_rendered(self, buf)?;
+
+        Ok(buf.len() - initial_length)
+    }
+
+    fn render_to_writer<W: ::std::io::Write>(&self, writer: &mut W) -> ::std::io::Result<()> {
+        match Self::HAS_POSTPROCESS {
+            true => self.render_buffered(writer),
+            false => writer.write_fmt(format_args!("{}", self)),
+        }
+    }
+
+    fn render_and_flush<W: ::std::io::Write>(&self, writer: &mut W) -> ::std::io::Result<()> {
+        self.render_to_writer(writer)?;
+
+        writer.flush()
+    }
+
+    fn render_buffered<W: ::std::io::Write>(&self, writer: &mut W) -> ::std::io::Result<()> {
+        let rendered = self.render().map_err(::std::io::Error::other)?;
+
+        writer.write_all(rendered.as_bytes())
+    }
+
+    #[cfg(feature = "heapless")]
+    fn render_heapless<const N: usize>(&self) -> Result<::heapless::String<N>, RenderError> {
+        let mut writer = HeaplessWriter {
+            rendered: ::heapless::String::new(),
+            is_capacity_exceeded: false,
+        };
+
+        match write_rendered(self, &mut writer) {
+            Ok(()) => Ok(writer.rendered),
+            Err(_) if writer.is_capacity_exceeded => Err(RenderError::CapacityExceeded),
+            Err(error) => Err(RenderError::Fmt(error)),
+        }
+    }
+
+    fn render_to_fmt_limited<W: ::core::fmt::Write>(
+        &self,
+        w: &mut W,
+        max_bytes: usize,
+    ) -> Result<(), RenderError> {
+        let mut writer = LimitedWriter {
+            writer: w,
+            remaining: max_bytes,
+            is_limit_exceeded: false,
+        };
+
+        match write_rendered(self, &mut writer) {
+            Ok(()) => Ok(()),
+            Err(_) if writer.is_limit_exceeded => Err(RenderError::LimitExceeded),
+            Err(error) => Err(RenderError::Fmt(error)),
+        }
+    }
+
+    #[cfg(feature = "mmap")]
+    fn render_to_mmap(&self, file: &::std::fs::File) -> ::std::io::Result<()> {
+        let mut writer = MmapWriter::new(file, self.estimated_size())?;
+
+        match write_rendered(self, &mut writer) {
+            Ok(()) => writer.finish(),
+            Err(_) => {
+                let error = writer
+                    .error
+                    .take()
+                    .unwrap_or_else(|| ::std::io::Error::other(::core::fmt::Error));
+
+                writer.finish().and(Err(error))
+            }
+        }
+    }
+
+    #[cfg(feature = "bytes")]
+    fn render_to_bytes_mut(&self, buf: &mut ::bytes::BytesMut) -> Result<(), ::core::fmt::Error> {
+        buf.reserve(self.estimated_size());
+
+        write_rendered(self, buf)
+    }
+}
+
+fn write_rendered<T, W>(template: &T, writer: &mut W) -> ::core::fmt::Result
+where
+    T: Remplate + ?Sized,
+    W: ::core::fmt::Write,
+{
+    match T::HAS_POSTPROCESS {
+        true => writer.write_str(&template.render()?),
+        false => writer.write_fmt(format_args!("{}", template)),
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum RenderError {
+    Fmt(::core::fmt::Error),
+    CapacityExceeded,
+    LimitExceeded,
+}
+
+impl ::core::fmt::Display for RenderError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self {
+            RenderError::Fmt(error) => error.fmt(f),
+            RenderError::CapacityExceeded => {
+                f.write_str("the rendered template exceeds the capacity")
+            }
+            RenderError::LimitExceeded => {
+                f.write_str("the rendered template exceeds the byte limit")
+            }
+        }
+    }
+}
+
+impl ::std::error::Error for RenderError {}
+
+struct LimitedWriter<'a, W> {
+    writer: &'a mut W,
+    remaining: usize,
+    is_limit_exceeded: bool,
+}
+
+impl<W: ::core::fmt::Write> ::core::fmt::Write for LimitedWriter<'_, W> {
+    fn write_str(&mut self, s: &str) -> ::core::fmt::Result {
+        if s.len() > self.remaining {
+            self.is_limit_exceeded = true;
+            return Err(::core::fmt::Error);
+        }
+
+        self.remaining -= s.len();
+        self.writer.write_str(s)
+    }
+}
+
+#[cfg(feature = "heapless")]
+struct HeaplessWriter<const N: usize> {
+    rendered: ::heapless::String<N>,
+    is_capacity_exceeded: bool,
+}
+
+#[cfg(feature = "heapless")]
+impl<const N: usize> ::core::fmt::Write for HeaplessWriter<N> {
+    fn write_str(&mut self, s: &str) -> ::core::fmt::Result {
+        self.rendered.push_str(s).map_err(|_| {
+            self.is_capacity_exceeded = true;
+            ::core::fmt::Error
+        })
+    }
+}
+
+#[cfg(feature = "hydration")]
+pub struct SpanWriter<'a>(pub &'a mut String);
+
+#[cfg(feature = "hydration")]
+impl SpanWriter<'_> {
+    pub fn position(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn write_str(&mut self, s: &str) -> ::core::fmt::Result {
+        ::core::fmt::Write::write_str(self.0, s)
+    }
+
+    pub fn write_fmt(&mut self, args: ::core::fmt::Arguments<'_>) -> ::core::fmt::Result {
+        ::core::fmt::Write::write_fmt(self.0, args)
+    }
+}
+
+#[cfg(feature = "hydration")]
+impl ::core::fmt::Write for SpanWriter<'_> {
+    fn write_str(&mut self, s: &str) -> ::core::fmt::Result {
+        SpanWriter::write_str(self, s)
+    }
+}
+
+pub struct OffsetWriter<'a, W> {
+    writer: &'a mut W,
+    position: usize,
+}
+
+impl<'a, W: ::core::fmt::Write> OffsetWriter<'a, W> {
+    pub fn new(writer: &'a mut W) -> Self {
+        Self {
+            writer,
+            position: 0,
+        }
+    }
+
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl<W: ::core::fmt::Write> ::core::fmt::Write for OffsetWriter<'_, W> {
+    fn write_str(&mut self, s: &str) -> ::core::fmt::Result {
+        self.writer.write_str(s)?;
+        self.position += s.len();
+
+        Ok(())
     }
 }
 
-pub use remplate_macros::Remplate;
+#[cfg(feature = "mmap")]
+struct MmapWriter<'a> {
+    file: &'a ::std::fs::File,
+    mmap: ::memmap2::MmapMut,
+    length: usize,
+    error: Option<::std::io::Error>,
+}
+
+#[cfg(feature = "mmap")]
+impl<'a> MmapWriter<'a> {
+    fn new(file: &'a ::std::fs::File, capacity: usize) -> ::std::io::Result<Self> {
+        Ok(Self {
+            file,
+            mmap: Self::map(file, capacity.max(1))?,
+            length: 0,
+            error: None,
+        })
+    }
+
+    fn map(file: &::std::fs::File, capacity: usize) -> ::std::io::Result<::memmap2::MmapMut> {
+        file.set_len(capacity as u64)?;
+
+        // SAFETY: the file is only modified through this mapping while rendering
+        unsafe { ::memmap2::MmapMut::map_mut(file) }
+    }
+
+    fn reserve(&mut self, additional: usize) -> ::std::io::Result<()> {
+        let required = self.length + additional;
+
+        if required > self.mmap.len() {
+            self.mmap.flush()?;
+            self.mmap = Self::map(self.file, required.max(self.mmap.len() * 2))?;
+        }
+
+        Ok(())
+    }
+
+    fn finish(self) -> ::std::io::Result<()> {
+        self.mmap.flush()?;
+        drop(self.mmap);
+
+        self.file.set_len(self.length as u64)
+    }
+}
+
+#[cfg(feature = "mmap")]
+impl ::core::fmt::Write for MmapWriter<'_> {
+    fn write_str(&mut self, s: &str) -> ::core::fmt::Result {
+        if let Err(error) = self.reserve(s.len()) {
+            self.error = Some(error);
+            return Err(::core::fmt::Error);
+        }
+
+        self.mmap[self.length..(self.length + s.len())].copy_from_slice(s.as_bytes());
+        self.length += s.len();
+
+        Ok(())
+    }
+}
+
+pub fn render_all<'a, T>(
+    items: impl IntoIterator<Item = &'a T>,
+) -> Result<String, ::core::fmt::Error>
+where
+    T: Remplate + 'a,
+{
+    let items: Vec<&T> = items.into_iter().collect();
+    let mut rendered =
+        ::std::string::String::with_capacity(items.iter().map(|item| item.estimated_size()).sum());
+
+    for item in items {
+        item.render_appending(&mut rendered)?;
+    }
+
+    Ok(rendered)
+}
+
+pub struct InlineTemplate<F>(pub F)
+where
+    F: Fn(&mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result;
+
+impl<F> ::core::fmt::Display for InlineTemplate<F>
+where
+    F: Fn(&mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result,
+{
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        (self.0)(f)
+    }
+}
+
+pub struct Concat<T>(pub T);
+
+const fn add_size_hints(
+    (minimum, maximum): (usize, Option<usize>),
+    (other_minimum, other_maximum): (usize, Option<usize>),
+) -> (usize, Option<usize>) {
+    let maximum = match (maximum, other_maximum) {
+        (Some(maximum), Some(other_maximum)) => maximum.checked_add(other_maximum),
+        _ => None,
+    };
+
+    (minimum.saturating_add(other_minimum), maximum)
+}
+
+macro_rules! concat_impl {
+    ($($name:ident $index:tt),+) => {
+        impl<$($name),+> ::core::fmt::Display for Concat<($($name,)+)>
+        where
+            $($name: Remplate),+
+        {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                $(write_rendered(&(self.0).$index, f)?;)+
+
+                Ok(())
+            }
+        }
+
+        impl<$($name),+> Remplate for Concat<($($name,)+)>
+        where
+            $($name: Remplate),+
+        {
+            const ESTIMATED_SIZE: usize = 0 $(+ $name::ESTIMATED_SIZE)+;
+            const SIZE_HINT: (usize, Option<usize>) = {
+                let size_hint = (0, Some(0));
+                $(let size_hint = add_size_hints(size_hint, $name::SIZE_HINT);)+
+
+                size_hint
+            };
+
+            fn estimated_size(&self) -> usize {
+                0 $(+ (self.0).$index.estimated_size())+
+            }
+        }
+    };
+}
+
+concat_impl!(A 0);
+concat_impl!(A 0, B 1);
+concat_impl!(A 0, B 1, C 2);
+concat_impl!(A 0, B 1, C 2, D 3);
+concat_impl!(A 0, B 1, C 2, D 3, E 4);
+concat_impl!(A 0, B 1, C 2, D 3, E 4, F 5);
+concat_impl!(A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+concat_impl!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+concat_impl!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8);
+concat_impl!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9);
+concat_impl!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10);
+concat_impl!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11);
+
+pub use remplate_macros::{remplate_inline, render_with, Remplate, Template};
+
+pub mod prelude {
+    pub use crate::{remplate_inline, render_all, render_with, Remplate, Template};
+}
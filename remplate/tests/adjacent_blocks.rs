@@ -0,0 +1,20 @@
+use remplate::Remplate;
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/adjacent_blocks.html")]
+struct AdjacentBlocks {
+    a: char,
+    b: char,
+    c: char,
+}
+
+#[test]
+fn render_adjacent_blocks_in_order() {
+    let template = AdjacentBlocks {
+        a: 'a',
+        b: 'b',
+        c: 'c',
+    };
+
+    assert_eq!(template.render().unwrap(), "abc");
+}
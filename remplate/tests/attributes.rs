@@ -0,0 +1,34 @@
+use remplate::Remplate;
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/attributes.html")]
+struct Input {
+    disabled: bool,
+    value: String,
+}
+
+#[test]
+fn render_boolean_attribute() {
+    let template = Input {
+        disabled: true,
+        value: String::from("value"),
+    };
+
+    assert_eq!(
+        template.render().unwrap(),
+        "<input disabled value=\"value\">\n"
+    );
+}
+
+#[test]
+fn render_valued_attribute_escaped() {
+    let template = Input {
+        disabled: false,
+        value: String::from("\"><script>"),
+    };
+
+    assert_eq!(
+        template.render().unwrap(),
+        "<input value=\"&quot;&gt;&lt;script&gt;\">\n"
+    );
+}
@@ -0,0 +1,34 @@
+use remplate::Remplate;
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/block_expressions.html")]
+struct Blocks {
+    c: bool,
+    n: u32,
+    items: Vec<&'static str>,
+}
+
+#[test]
+fn render_block_valued_expressions() {
+    let template = Blocks {
+        c: true,
+        n: 1,
+        items: vec!["x", "y"],
+    };
+
+    assert_eq!(
+        template.render().unwrap(),
+        "<p>a</p><p>one</p><p></p><ul><li>x</li><li>y</li></ul>\n"
+    );
+
+    let template = Blocks {
+        c: false,
+        n: 2,
+        items: vec![],
+    };
+
+    assert_eq!(
+        template.render().unwrap(),
+        "<p>b</p><p>many</p><p></p><ul></ul>\n"
+    );
+}
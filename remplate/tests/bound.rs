@@ -0,0 +1,20 @@
+use std::fmt::Display;
+
+use remplate::Remplate;
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/generic_list.html", bound = "T: Display")]
+struct List<T> {
+    items: Vec<T>,
+}
+
+#[test]
+fn render_generic_struct_with_bound() {
+    let numbers = List { items: vec![1, 2] };
+    let words = List {
+        items: vec!["a", "b"],
+    };
+
+    assert_eq!(numbers.render().unwrap(), "<ul><li>1</li><li>2</li></ul>\n");
+    assert_eq!(words.render().unwrap(), "<ul><li>a</li><li>b</li></ul>\n");
+}
@@ -0,0 +1,18 @@
+use std::fmt::Display;
+
+use remplate::Remplate;
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/boxed_display.html")]
+struct Boxed {
+    widget: Box<dyn Display>,
+}
+
+#[test]
+fn render_boxed_display_field() {
+    let template = Boxed {
+        widget: Box::new("knob"),
+    };
+
+    assert_eq!(template.render().unwrap(), "<p>knob</p><p>    knob</p>\n");
+}
@@ -0,0 +1,22 @@
+#![cfg(feature = "bytes")]
+
+use remplate::Remplate;
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/text.html")]
+struct Text {
+    text: &'static str,
+}
+
+#[test]
+fn render_to_bytes_mut() {
+    let template = Text { text: "Text" };
+
+    let mut buf = bytes::BytesMut::from(&b"<!-- prefix -->"[..]);
+    template.render_to_bytes_mut(&mut buf).unwrap();
+
+    assert_eq!(
+        &buf[..],
+        format!("<!-- prefix -->{}", template.render().unwrap()).as_bytes()
+    );
+}
@@ -0,0 +1,34 @@
+use remplate::Remplate;
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/text.html", check_html = true)]
+struct Balanced {
+    text: &'static str,
+}
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/unbalanced.html", check_html = true)]
+struct Unbalanced {
+    text: &'static str,
+}
+
+#[test]
+fn render_balanced_html() {
+    let template = Balanced { text: "Text" };
+
+    assert_eq!(template.render().unwrap(), template.to_string());
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "unclosed `<div>` at byte 0")]
+fn render_unbalanced_html() {
+    let _ = Unbalanced { text: "Text" }.render();
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "unclosed `<div>` at byte 0")]
+fn render_unbalanced_html_to_writer() {
+    let _ = Unbalanced { text: "Text" }.render_to_writer(&mut Vec::new());
+}
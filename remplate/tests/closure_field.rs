@@ -0,0 +1,21 @@
+use remplate::Remplate;
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/closure_field.html")]
+struct Lazy {
+    render_extra: Box<dyn Fn() -> String>,
+    greet: fn(String) -> String,
+}
+
+#[test]
+fn render_closure_fields() {
+    let template = Lazy {
+        render_extra: Box::new(|| String::from("Extra")),
+        greet: |name| format!("Hi {}", name),
+    };
+
+    assert_eq!(
+        template.render().unwrap(),
+        "<p>Extra</p><p>  Hi World</p>\n"
+    );
+}
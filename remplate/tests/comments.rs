@@ -0,0 +1,27 @@
+use remplate::Remplate;
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/comment.html")]
+struct Commented {
+    text: &'static str,
+}
+
+#[test]
+fn render_without_comments() {
+    let template = Commented { text: "Text" };
+
+    assert_eq!(template.render().unwrap(), "<p>Text</p>\n");
+}
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/block_comment.html")]
+struct BlockCommented {
+    text: &'static str,
+}
+
+#[test]
+fn render_without_block_comments() {
+    let template = BlockCommented { text: "Text" };
+
+    assert_eq!(template.render().unwrap(), "<p>Text</p>\n");
+}
@@ -0,0 +1,74 @@
+use remplate::{Concat, Remplate};
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/row.html")]
+struct Row {
+    name: &'static str,
+    count: u32,
+}
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/empty.html")]
+struct Empty;
+
+#[test]
+fn render_concatenated_templates() {
+    let first = Row {
+        name: "first",
+        count: 1,
+    };
+    let second = Row {
+        name: "second",
+        count: 2,
+    };
+    let expected = first.render().unwrap() + &second.render().unwrap();
+
+    assert_eq!(Concat((first, second)).render().unwrap(), expected);
+}
+
+#[test]
+fn sum_estimated_sizes() {
+    assert_eq!(
+        <Concat<(Row, Empty, Row)>>::ESTIMATED_SIZE,
+        2 * Row::ESTIMATED_SIZE + Empty::ESTIMATED_SIZE
+    );
+}
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/size_hint_static.html")]
+struct Heading;
+
+#[test]
+fn sum_size_hints() {
+    assert_eq!(Heading::SIZE_HINT, (16, Some(16)));
+    assert_eq!(
+        <Concat<(Heading, Empty, Heading)>>::SIZE_HINT,
+        (32, Some(32))
+    );
+    assert_eq!(
+        <Concat<(Heading, Row)>>::SIZE_HINT,
+        (16 + Row::SIZE_HINT.0, None)
+    );
+}
+
+fn uppercase(rendered: String) -> String {
+    rendered.to_uppercase()
+}
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/text.html", postprocess = "uppercase")]
+struct Shouting {
+    text: &'static str,
+}
+
+#[test]
+fn postprocess_concatenated_parts() {
+    let shouting = Shouting { text: "quiet" };
+    let row = Row {
+        name: "row",
+        count: 1,
+    };
+    let expected = shouting.render().unwrap() + &row.render().unwrap();
+
+    assert_eq!(Concat((shouting, row)).render().unwrap(), expected);
+}
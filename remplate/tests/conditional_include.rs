@@ -0,0 +1,31 @@
+use remplate::Remplate;
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/conditional_include.html")]
+struct Page {
+    banner: Option<&'static str>,
+    text: &'static str,
+}
+
+#[test]
+fn render_conditional_include_with_banner() {
+    let template = Page {
+        banner: Some("Sale"),
+        text: "Welcome",
+    };
+
+    assert_eq!(
+        template.render().unwrap(),
+        "<main><aside>Sale</aside><p>Welcome</p></main>\n"
+    );
+}
+
+#[test]
+fn render_conditional_include_without_banner() {
+    let template = Page {
+        banner: None,
+        text: "Welcome",
+    };
+
+    assert_eq!(template.render().unwrap(), "<main><p>Welcome</p></main>\n");
+}
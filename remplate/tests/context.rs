@@ -0,0 +1,52 @@
+use remplate::Remplate;
+
+struct Person {
+    name: &'static str,
+    age: u32,
+}
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/context.html", context = "Person")]
+struct PersonTemplate;
+
+#[test]
+fn render_against_different_contexts() {
+    let first = Person {
+        name: "Alice",
+        age: 32,
+    };
+    let second = Person {
+        name: "Bob",
+        age: 45,
+    };
+
+    assert_eq!(
+        PersonTemplate::render_ctx(&first).unwrap(),
+        "<p>Alice (32)</p>\n"
+    );
+    assert_eq!(
+        PersonTemplate::render_ctx(&second).unwrap(),
+        "<p>Bob (45)</p>\n"
+    );
+}
+
+#[derive(Remplate)]
+#[remplate(
+    path = "tests/templates/context.html",
+    context = "Person",
+    method = "to_html"
+)]
+struct NamedPersonTemplate;
+
+#[test]
+fn render_with_custom_method_name() {
+    let person = Person {
+        name: "Carol",
+        age: 27,
+    };
+
+    assert_eq!(
+        NamedPersonTemplate::to_html(&person).unwrap(),
+        "<p>Carol (27)</p>\n"
+    );
+}
@@ -0,0 +1,55 @@
+use remplate::Remplate;
+
+struct PageData {
+    title: &'static str,
+    tags: Vec<&'static str>,
+    views: u32,
+}
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/context_field.html")]
+struct Page {
+    #[remplate_context]
+    data: PageData,
+}
+
+#[test]
+fn render_with_context_field() {
+    let template = Page {
+        data: PageData {
+            title: "Title",
+            tags: vec!["a", "b"],
+            views: 7,
+        },
+    };
+
+    assert_eq!(
+        template.render().unwrap(),
+        "<h1>Title</h1><i>a</i><i>b</i><p>2   7</p>\n"
+    );
+}
+
+const MAX_VIEWS: u32 = 100;
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/context_field_scope.html")]
+struct ScopedPage {
+    #[remplate_context]
+    data: PageData,
+}
+
+#[test]
+fn render_context_field_after_closed_scope() {
+    let template = ScopedPage {
+        data: PageData {
+            title: "Title",
+            tags: vec!["a", "b"],
+            views: 7,
+        },
+    };
+
+    assert_eq!(
+        template.render().unwrap(),
+        "<h1>Title</h1><h2>a</h2><h3>Title</h3><p>100</p>\n"
+    );
+}
@@ -0,0 +1,53 @@
+use remplate::Remplate;
+
+#[derive(Remplate)]
+#[remplate(
+    path = "tests/templates/contextual_escape.html",
+    contextual_escape = true
+)]
+struct Contextual {
+    value: &'static str,
+}
+
+#[test]
+fn escape_by_html_context() {
+    let template = Contextual {
+        value: "javascript:alert(\"<x>\")",
+    };
+
+    let expected = concat!(
+        "<a href=\"\">javascript:alert(&quot;&lt;x&gt;&quot;)</a>\n",
+        "<script>const value = \"javascript:alert(\\\"\\u003Cx\\u003E\\\")\";</script>\n",
+        "<style>a::after { content: \"javascript\\3A alert\\28 \\22 \\3C x\\3E \\22 \\29 \"; }</style>\n",
+        "<script>const short = \"javascript:alert(\\\"\\u003Cx\\u003E\\\")\";</script>\n",
+        "<input value= title=>\n",
+        "<p>javascript:alert(\\\"\\u003Cx\\u003E\\\") javascript:alert(\"<x>\")</p>\n",
+    );
+
+    assert_eq!(template.render().unwrap(), expected);
+}
+
+#[test]
+fn escape_safe_value_in_attribute() {
+    let template = Contextual {
+        value: "/home?a=1&b=2",
+    };
+
+    let rendered = template.render().unwrap();
+
+    assert!(rendered.starts_with("<a href=\"/home?a=1&amp;b=2\">/home?a=1&amp;b=2</a>\n"));
+}
+
+#[test]
+fn escape_unquoted_attribute_value() {
+    let template = Contextual {
+        value: "x onfocus=alert(1)",
+    };
+
+    let rendered = template.render().unwrap();
+
+    assert!(rendered.contains(concat!(
+        "<input value=x&#x20;onfocus&#x3D;alert&#x28;1&#x29; ",
+        "title=x&#x20;onfocus&#x3D;alert&#x28;1&#x29;>\n",
+    )));
+}
@@ -0,0 +1,21 @@
+use remplate::Remplate;
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/control_characters.html")]
+struct ControlCharacters {
+    first: &'static str,
+    second: &'static str,
+}
+
+#[test]
+fn render_control_characters_unchanged() {
+    let template = ControlCharacters {
+        first: "first",
+        second: "second",
+    };
+
+    assert_eq!(
+        template.render().unwrap().as_bytes(),
+        b"<p>\0first\x0b\x01\xc3\xbc\x1b[0msecond\r\t\x7f</p>\n"
+    );
+}
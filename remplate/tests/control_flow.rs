@@ -0,0 +1,48 @@
+use remplate::Remplate;
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/if_let.html")]
+struct IfLet {
+    subtitle: Option<&'static str>,
+}
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/while_let.html")]
+struct WhileLet {
+    items: Vec<u32>,
+}
+
+#[test]
+fn render_if_let_matching() {
+    let template = IfLet {
+        subtitle: Some("Subtitle"),
+    };
+
+    assert_eq!(template.render().unwrap(), "<p><em>Subtitle</em></p>\n");
+}
+
+#[test]
+fn render_if_let_not_matching() {
+    let template = IfLet { subtitle: None };
+
+    assert_eq!(template.render().unwrap(), "<p>none</p>\n");
+}
+
+#[test]
+fn render_while_let() {
+    let template = WhileLet {
+        items: vec![1, 2, 3],
+    };
+
+    assert_eq!(
+        template.render().unwrap(),
+        "<ul><li>1</li><li>2</li><li>3</li></ul>\n"
+    );
+}
+
+#[test]
+fn render_while_let_without_items() {
+    let template = WhileLet { items: vec![] };
+
+    assert_eq!(template.render().unwrap(), "<ul></ul>\n");
+}
@@ -0,0 +1,21 @@
+#![cfg(feature = "chrono")]
+
+use remplate::Remplate;
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/date.html")]
+struct Dated {
+    date: chrono::NaiveDate,
+}
+
+#[test]
+fn render_date_filter() {
+    let template = Dated {
+        date: chrono::NaiveDate::from_ymd_opt(2024, 3, 9).unwrap(),
+    };
+
+    assert_eq!(
+        template.render().unwrap(),
+        "<time>2024-03-09</time><p>09&lt;03&gt;</p>\n"
+    );
+}
@@ -0,0 +1,58 @@
+#![cfg(feature = "debug-render")]
+
+use remplate::Remplate;
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/debug_render.html")]
+struct Annotated {
+    title: &'static str,
+    count: usize,
+    note: Option<&'static str>,
+}
+
+#[test]
+fn render_debug_annotates_expressions() {
+    let template = Annotated {
+        title: "Title",
+        count: 7,
+        note: None,
+    };
+
+    assert_eq!(
+        template.render_debug().unwrap(),
+        concat!(
+            "<h1><!--{self.title}-->Title<!--/--></h1>\n",
+            "<p><!--{self.count}-->  7<!--/--> items<!--{self.note}--><!--/--></p>\n",
+        )
+    );
+    assert_eq!(
+        template.render().unwrap(),
+        "<h1>Title</h1>\n<p>  7 items</p>\n"
+    );
+}
+
+struct Person {
+    name: &'static str,
+    age: u32,
+}
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/context.html", context = "Person")]
+struct PersonTemplate;
+
+#[test]
+fn render_context_debug() {
+    let person = Person {
+        name: "Alice",
+        age: 32,
+    };
+
+    assert_eq!(
+        PersonTemplate::render_ctx_debug(&person).unwrap(),
+        "<p><!--{ctx.name}-->Alice<!--/--> (<!--{ctx.age}-->32<!--/-->)</p>\n"
+    );
+    assert_eq!(
+        PersonTemplate::render_ctx(&person).unwrap(),
+        "<p>Alice (32)</p>\n"
+    );
+}
@@ -0,0 +1,35 @@
+use remplate::Remplate;
+
+struct Row {
+    name: &'static str,
+    count: u32,
+}
+
+#[derive(Remplate)]
+#[remplate(each = "tests/templates/each_row.html")]
+struct Rows(Vec<Row>);
+
+#[test]
+fn render_template_for_each_element() {
+    let template = Rows(vec![
+        Row {
+            name: "apples",
+            count: 3,
+        },
+        Row {
+            name: "pears",
+            count: 5,
+        },
+    ]);
+
+    assert_eq!(
+        template.render().unwrap(),
+        "<li>apples: 3</li>\n<li>pears: 5</li>\n"
+    );
+    assert_eq!(template.estimated_size(), Rows::ESTIMATED_SIZE * 2);
+}
+
+#[test]
+fn render_template_for_no_elements() {
+    assert_eq!(Rows(vec![]).render().unwrap(), "");
+}
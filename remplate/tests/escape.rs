@@ -0,0 +1,30 @@
+use remplate::Remplate;
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/escape.html", trim_blocks = true)]
+struct Escaped {
+    text: &'static str,
+    tags: Vec<&'static str>,
+}
+
+#[test]
+fn render_html_and_js_escape_contexts() {
+    let template = Escaped {
+        text: "<b>\"Tom\" & 'Jerry'</b>",
+        tags: vec!["<i>"],
+    };
+
+    assert_eq!(
+        template.render().unwrap(),
+        concat!(
+            "<p><b>\"Tom\" & 'Jerry'</b></p>\n",
+            "<script>\n",
+            "const text = \"\\u003Cb\\u003E\\\"Tom\\\" \\u0026 \\'Jerry\\'\\u003C/b\\u003E\";\n",
+            "</script>\n",
+            "<p>&lt;b&gt;&quot;Tom&quot; &amp; &#x27;Jerry&#x27;&lt;/b&gt;</p>\n",
+            "<p>&lt;i&gt;</p>\n",
+            "<p><i></p>\n",
+            "<p>[&quot;&lt;i&gt;&quot;]</p>\n",
+        )
+    );
+}
@@ -0,0 +1,73 @@
+use remplate::Remplate;
+
+#[derive(Remplate)]
+#[remplate(
+    path = "tests/templates/list.html",
+    estimated_size_expr = "self.items.len() * 32 + 128"
+)]
+struct List {
+    items: Vec<&'static str>,
+}
+
+#[test]
+fn estimated_size_from_expression() {
+    let template = List {
+        items: vec!["a"; 10],
+    };
+
+    assert_eq!(template.estimated_size(), 10 * 32 + 128);
+}
+
+#[test]
+fn render_with_capacity_from_expression() {
+    let template = List {
+        items: vec!["a"; 10],
+    };
+
+    let rendered = template.render().unwrap();
+
+    assert_eq!(rendered, format!("<ul>{}</ul>\n", "<li>a</li>".repeat(10)));
+    assert!(rendered.capacity() >= 10 * 32 + 128);
+}
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/empty.html")]
+struct Empty;
+
+#[test]
+fn estimated_size_of_empty_template_is_clamped() {
+    const { assert!(Empty::ESTIMATED_SIZE >= 16) };
+    assert_eq!(Empty.render().unwrap(), "");
+}
+
+struct Tags {
+    items: Vec<&'static str>,
+}
+
+impl std::fmt::Display for Tags {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.items
+            .iter()
+            .try_for_each(|item| write!(f, "#{} ", item))
+    }
+}
+
+impl Remplate for Tags {
+    const ESTIMATED_SIZE: usize = 16;
+
+    fn estimated_size(&self) -> usize {
+        Self::ESTIMATED_SIZE + self.items.len() * 32
+    }
+}
+
+#[test]
+fn render_with_capacity_from_overridden_estimated_size() {
+    let template = Tags {
+        items: vec!["a"; 8],
+    };
+
+    let rendered = template.render().unwrap();
+
+    assert_eq!(rendered, "#a ".repeat(8));
+    assert!(rendered.capacity() >= Tags::ESTIMATED_SIZE + 8 * 32);
+}
@@ -0,0 +1,37 @@
+use remplate::Remplate;
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/expression_list.html")]
+struct Listed {
+    x: u32,
+    y: &'static str,
+    z: &'static str,
+}
+
+#[test]
+fn render_expression_list_as_concatenation() {
+    let template = Listed {
+        x: 1,
+        y: "ab",
+        z: "c",
+    };
+
+    assert_eq!(
+        template.render().unwrap(),
+        "<p>1ab</p>\n<p>   1  ab\"c\"</p>\n"
+    );
+}
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/debug_array.html")]
+struct DebugArray {
+    a: u32,
+    b: u32,
+}
+
+#[test]
+fn render_debug_array_as_single_value() {
+    let template = DebugArray { a: 1, b: 2 };
+
+    assert_eq!(template.render().unwrap(), "<p>[1, 2]</p>\n");
+}
@@ -0,0 +1,29 @@
+use remplate::Remplate;
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/layout.html")]
+struct Layout;
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/page.html")]
+struct Page {
+    text: &'static str,
+}
+
+#[test]
+fn render_default_blocks() {
+    assert_eq!(
+        Layout.render().unwrap(),
+        "<title>Default</title>\n<main><p>Empty</p></main>\n"
+    );
+}
+
+#[test]
+fn render_overridden_block() {
+    let template = Page { text: "Text" };
+
+    assert_eq!(
+        template.render().unwrap(),
+        "<title>Default</title>\n<main><p>Text</p></main>\n"
+    );
+}
@@ -0,0 +1,66 @@
+use remplate::Remplate;
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/export.json")]
+struct JsonExport {
+    name: &'static str,
+    note: &'static str,
+    count: usize,
+}
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/export.csv")]
+struct CsvExport {
+    name: &'static str,
+    note: &'static str,
+    tags: Vec<&'static str>,
+}
+
+#[test]
+fn render_json_template_with_json_escaping() {
+    let template = JsonExport {
+        name: "\"Tom\"",
+        note: "line\nbreak\\",
+        count: 3,
+    };
+
+    assert_eq!(
+        template.render().unwrap(),
+        "[\"\\\"Tom\\\"\", \"line\\nbreak\\\\\", 3]\n"
+    );
+}
+
+#[test]
+fn render_csv_template_with_csv_quoting() {
+    let template = CsvExport {
+        name: "Tom",
+        note: "a, \"b\"",
+        tags: vec!["x", "y"],
+    };
+
+    assert_eq!(
+        template.render().unwrap(),
+        "name,note,tags\nTom,\"a, \"\"b\"\"\",a, \"b\",\"x, y\"\n"
+    );
+}
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/text.html", escape = "html")]
+struct EscapedText {
+    text: &'static str,
+}
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/text.html")]
+struct Text {
+    text: &'static str,
+}
+
+#[test]
+fn render_html_template_with_explicit_escape() {
+    assert_eq!(
+        EscapedText { text: "<b>" }.render().unwrap(),
+        "<p>&lt;b&gt;</p>\n"
+    );
+    assert_eq!(Text { text: "<b>" }.render().unwrap(), "<p><b></p>\n");
+}
@@ -0,0 +1,60 @@
+use remplate::Remplate;
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/attr.html")]
+struct Link {
+    href: String,
+    label: &'static str,
+}
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/join.html")]
+struct Tags {
+    tags: Vec<&'static str>,
+}
+
+#[test]
+fn render_attr_filter_escaping() {
+    let template = Link {
+        href: String::from("\"><script>alert(1)</script>"),
+        label: "Link",
+    };
+
+    assert_eq!(
+        template.render().unwrap(),
+        "<a href=\"&quot;&gt;&lt;script&gt;alert(1)&lt;/script&gt;\">Link</a>\n"
+    );
+}
+
+#[test]
+fn render_attr_filter_javascript_url() {
+    let template = Link {
+        href: String::from("javascript:alert(1)"),
+        label: "Link",
+    };
+
+    assert_eq!(template.render().unwrap(), "<a href=\"\">Link</a>\n");
+}
+
+#[test]
+fn render_join_filter_without_values() {
+    let template = Tags { tags: vec![] };
+
+    assert_eq!(template.render().unwrap(), "<p></p>\n");
+}
+
+#[test]
+fn render_join_filter_with_single_value() {
+    let template = Tags { tags: vec!["a"] };
+
+    assert_eq!(template.render().unwrap(), "<p>a</p>\n");
+}
+
+#[test]
+fn render_join_filter_with_values() {
+    let template = Tags {
+        tags: vec!["a", "b", "c"],
+    };
+
+    assert_eq!(template.render().unwrap(), "<p>a, b, c</p>\n");
+}
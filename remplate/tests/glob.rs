@@ -0,0 +1,58 @@
+use remplate::Remplate;
+
+#[derive(Remplate)]
+#[remplate(
+    path = "tests/templates/text.html",
+    glob = "tests/templates/themes/*.html"
+)]
+struct Themed {
+    text: &'static str,
+}
+
+#[test]
+fn render_matching_themes() {
+    let template = Themed { text: "Text" };
+
+    assert_eq!(
+        template.render_themes("dark").unwrap().unwrap(),
+        "<body class=\"dark\">Text</body>\n"
+    );
+    assert_eq!(
+        template.render_themes("light").unwrap().unwrap(),
+        "<body class=\"light\">Text</body>\n"
+    );
+}
+
+#[test]
+fn render_unknown_theme() {
+    let template = Themed { text: "Text" };
+
+    assert!(template.render_themes("sepia").is_none());
+}
+
+#[test]
+fn render_default_template_next_to_themes() {
+    let template = Themed { text: "Text" };
+
+    assert_eq!(template.render().unwrap(), "<p>Text</p>\n");
+}
+
+#[derive(Remplate)]
+#[remplate(
+    path = "tests/templates/text.html",
+    glob = "tests/templates/themes/*.html",
+    method = "render_theme"
+)]
+struct NamedThemed {
+    text: &'static str,
+}
+
+#[test]
+fn render_theme_with_named_method() {
+    let template = NamedThemed { text: "Text" };
+
+    assert_eq!(
+        template.render_theme("dark").unwrap().unwrap(),
+        "<body class=\"dark\">Text</body>\n"
+    );
+}
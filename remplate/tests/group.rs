@@ -0,0 +1,21 @@
+use remplate::Remplate;
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/group.html")]
+struct Amounts {
+    amount: i64,
+    price: f64,
+}
+
+#[test]
+fn render_grouped_numbers() {
+    let template = Amounts {
+        amount: -1234567,
+        price: 1234.5,
+    };
+
+    assert_eq!(
+        template.render().unwrap(),
+        "<p>-1_234_567</p><p>1,234.5</p>\n"
+    );
+}
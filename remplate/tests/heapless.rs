@@ -0,0 +1,28 @@
+#![cfg(feature = "heapless")]
+
+use remplate::{Remplate, RenderError};
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/text.html")]
+struct Text {
+    text: &'static str,
+}
+
+#[test]
+fn render_into_heapless_string() {
+    let template = Text { text: "Text" };
+
+    let rendered = template.render_heapless::<32>().unwrap();
+
+    assert_eq!(rendered.as_str(), template.render().unwrap());
+}
+
+#[test]
+fn render_into_too_small_heapless_string() {
+    let template = Text { text: "Text" };
+
+    assert_eq!(
+        template.render_heapless::<8>(),
+        Err(RenderError::CapacityExceeded)
+    );
+}
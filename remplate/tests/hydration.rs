@@ -0,0 +1,58 @@
+#![cfg(feature = "hydration")]
+
+use remplate::Remplate;
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/hydration.html")]
+struct Spanned {
+    title: &'static str,
+    items: Vec<u32>,
+}
+
+#[test]
+fn render_with_spans_of_dynamic_parts() {
+    let template = Spanned {
+        title: "Title",
+        items: vec![1, 20],
+    };
+
+    let (rendered, spans) = template.render_with_spans().unwrap();
+
+    assert_eq!(rendered, template.render().unwrap());
+    assert!(rendered.ends_with("<p>Title</p>\n"));
+    assert_eq!(
+        spans
+            .iter()
+            .map(|span| &rendered[span.clone()])
+            .collect::<Vec<_>>(),
+        vec!["Title", "  1", " 20"]
+    );
+}
+
+struct Person {
+    name: &'static str,
+    age: u32,
+}
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/context.html", context = "Person")]
+struct PersonTemplate;
+
+#[test]
+fn render_context_with_spans() {
+    let person = Person {
+        name: "Alice",
+        age: 32,
+    };
+
+    let (rendered, spans) = PersonTemplate::render_ctx_with_spans(&person).unwrap();
+
+    assert_eq!(rendered, PersonTemplate::render_ctx(&person).unwrap());
+    assert_eq!(
+        spans
+            .iter()
+            .map(|span| &rendered[span.clone()])
+            .collect::<Vec<_>>(),
+        vec!["Alice", "32"]
+    );
+}
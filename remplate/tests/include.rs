@@ -0,0 +1,42 @@
+use remplate::Remplate;
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/table.html")]
+struct Table {
+    rows: Vec<(&'static str, u32)>,
+}
+
+#[test]
+fn render_include_with_parameters() {
+    let template = Table {
+        rows: vec![("a", 1), ("b", 20)],
+    };
+
+    assert_eq!(
+        template.render().unwrap(),
+        concat!(
+            "<table>",
+            "<tr><td>a</td><td>  1</td></tr>\n",
+            "<tr><td>b</td><td> 20</td></tr>\n",
+            "</table>\n"
+        )
+    );
+}
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/large_page.html")]
+struct LargePage {
+    text: &'static str,
+}
+
+#[test]
+fn estimated_size_includes_partial() {
+    const { assert!(LargePage::ESTIMATED_SIZE >= include_str!("templates/large_partial.html").len()) };
+
+    let template = LargePage { text: "Text" };
+
+    assert!(template
+        .render()
+        .unwrap()
+        .ends_with(include_str!("templates/large_partial.html")));
+}
@@ -0,0 +1,22 @@
+use remplate::Remplate;
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/include_static.html")]
+struct Styled {
+    text: &'static str,
+}
+
+#[test]
+fn render_included_static_file() {
+    let template = Styled { text: "Text" };
+
+    assert_eq!(
+        template.render().unwrap(),
+        "<style>body { color: \"red\"; }\n</style>Text\n"
+    );
+}
+
+#[test]
+fn estimated_size_includes_static_file() {
+    assert!(Styled::ESTIMATED_SIZE >= include_str!("templates/styles.css").len());
+}
@@ -0,0 +1,23 @@
+use std::collections::HashMap;
+
+use remplate::Remplate;
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/indexing.html")]
+struct Indexed {
+    scores: HashMap<&'static str, u32>,
+    items: Vec<&'static str>,
+}
+
+#[test]
+fn render_indexed_expressions() {
+    let template = Indexed {
+        scores: HashMap::from([("alice", 3), ("bob", 12)]),
+        items: vec!["a", "b"],
+    };
+
+    assert_eq!(
+        template.render().unwrap(),
+        "<p>3</p><p>      12</p><p>b</p>\n"
+    );
+}
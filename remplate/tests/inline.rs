@@ -0,0 +1,29 @@
+use remplate::remplate_inline;
+
+fn bold(x: &str) -> String {
+    remplate_inline!("<b>{x}</b>").to_string()
+}
+
+#[test]
+fn render_inline_template() {
+    assert_eq!(bold("Text"), "<b>Text</b>");
+}
+
+#[test]
+fn render_inline_template_with_control_flow() {
+    let items = ["a", "b"];
+
+    let list = remplate_inline!("<ul>{ for item in items { }<li>{ item }</li>{ } }</ul>");
+
+    assert_eq!(list.to_string(), "<ul><li>a</li><li>b</li></ul>");
+}
+
+#[test]
+fn render_empty_code_block_as_nothing() {
+    assert_eq!(remplate_inline!("a{}b").to_string(), "ab");
+}
+
+#[test]
+fn render_escaped_brace_before_empty_code_block() {
+    assert_eq!(remplate_inline!("{{}x}").to_string(), "{x}");
+}
@@ -0,0 +1,17 @@
+use remplate::Remplate;
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/interpolation_marker.html")]
+struct Marked {
+    value: u32,
+}
+
+#[test]
+fn render_explicit_and_implicit_interpolation() {
+    let template = Marked { value: 42 };
+
+    assert_eq!(
+        template.render().unwrap(),
+        "<p>42</p><p>42</p><p></p><p>  42</p>\n"
+    );
+}
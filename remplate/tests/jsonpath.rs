@@ -0,0 +1,27 @@
+#![cfg(feature = "serde_json")]
+
+use remplate::Remplate;
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/jsonpath.html")]
+struct Data {
+    data: serde_json::Value,
+}
+
+#[test]
+fn render_jsonpath_present() {
+    let template = Data {
+        data: serde_json::json!({ "user": { "name": "Ferris" } }),
+    };
+
+    assert_eq!(template.render().unwrap(), "<p>Ferris</p>\n");
+}
+
+#[test]
+fn render_jsonpath_missing() {
+    let template = Data {
+        data: serde_json::json!({ "user": {} }),
+    };
+
+    assert_eq!(template.render().unwrap(), "<p></p>\n");
+}
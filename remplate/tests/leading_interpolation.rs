@@ -0,0 +1,14 @@
+use remplate::Remplate;
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/leading_interpolation.html")]
+struct LeadingInterpolation {
+    title: &'static str,
+}
+
+#[test]
+fn render_template_starting_with_interpolation() {
+    let template = LeadingInterpolation { title: "Title" };
+
+    assert_eq!(template.render().unwrap(), "Title</h1>\n");
+}
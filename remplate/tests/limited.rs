@@ -0,0 +1,44 @@
+use remplate::{Remplate, RenderError};
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/text.html")]
+struct Text {
+    text: &'static str,
+}
+
+#[test]
+fn render_within_byte_limit() {
+    let template = Text { text: "Text" };
+    let mut output = String::new();
+
+    template.render_to_fmt_limited(&mut output, 32).unwrap();
+
+    assert_eq!(output, template.render().unwrap());
+}
+
+#[test]
+fn render_exactly_at_byte_limit() {
+    let template = Text { text: "Text" };
+    let expected = template.render().unwrap();
+    let mut output = String::new();
+
+    template
+        .render_to_fmt_limited(&mut output, expected.len())
+        .unwrap();
+
+    assert_eq!(output, expected);
+}
+
+#[test]
+fn render_exceeding_byte_limit() {
+    let template = Text {
+        text: "A text that is longer than the limit",
+    };
+    let mut output = String::new();
+
+    assert_eq!(
+        template.render_to_fmt_limited(&mut output, 16),
+        Err(RenderError::LimitExceeded)
+    );
+    assert!(output.len() <= 16);
+}
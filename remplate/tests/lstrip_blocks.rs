@@ -0,0 +1,70 @@
+use remplate::Remplate;
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/lstrip_blocks.html", lstrip_blocks = true)]
+struct Lstripped {
+    items: Vec<&'static str>,
+}
+
+#[derive(Remplate)]
+#[remplate(
+    path = "tests/templates/lstrip_blocks.html",
+    trim_blocks = true,
+    lstrip_blocks = true
+)]
+struct LstrippedAndTrimmed {
+    items: Vec<&'static str>,
+}
+
+#[derive(Remplate)]
+#[remplate(
+    path = "tests/templates/lstrip_blocks_tabs.html",
+    trim_blocks = true,
+    lstrip_blocks = true
+)]
+struct TabsLstrippedAndTrimmed {
+    items: Vec<&'static str>,
+}
+
+#[test]
+fn render_with_lstrip_blocks() {
+    let template = Lstripped {
+        items: vec!["a", "b"],
+    };
+
+    assert_eq!(
+        template.render().unwrap(),
+        "<ul>\n\n    <li>a</li>\n\n    <li>b</li>\n\n</ul>\n"
+    );
+}
+
+#[test]
+fn render_with_lstrip_and_trim_blocks() {
+    let template = LstrippedAndTrimmed {
+        items: vec!["a", "b"],
+    };
+
+    assert_eq!(
+        template.render().unwrap(),
+        "<ul>\n    <li>a</li>\n    <li>b</li>\n</ul>\n"
+    );
+}
+
+#[test]
+fn render_tab_and_space_indentation_alike() {
+    let spaces = LstrippedAndTrimmed {
+        items: vec!["a", "b"],
+    };
+    let tabs = TabsLstrippedAndTrimmed {
+        items: vec!["a", "b"],
+    };
+
+    assert_eq!(
+        tabs.render().unwrap(),
+        spaces.render().unwrap().replace("    ", "\t")
+    );
+    assert_eq!(
+        tabs.render().unwrap(),
+        "<ul>\n\t<li>a</li>\n\t<li>b</li>\n</ul>\n"
+    );
+}
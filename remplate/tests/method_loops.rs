@@ -0,0 +1,36 @@
+use remplate::Remplate;
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/method_loops.html")]
+struct Text {
+    text: &'static str,
+    separator: String,
+}
+
+impl Text {
+    fn lines(&self) -> impl Iterator<Item = &str> {
+        self.text.lines()
+    }
+
+    fn words<'a>(&'a self, separator: &'a str) -> impl Iterator<Item = &'a str> {
+        self.text.split(separator)
+    }
+}
+
+#[test]
+fn render_loops_over_method_iterators() {
+    let template = Text {
+        text: "ab\nc",
+        separator: "\n".to_string(),
+    };
+
+    assert_eq!(
+        template.render().unwrap(),
+        concat!(
+            "<p>ab</p><p>c</p>\n",
+            "<ol><li value=\"0\">ab</li><li value=\"1\">c</li></ol>\n",
+            "<ul><li>AB</li></ul>\n",
+            "<i>2</i><i>1</i>\n",
+        )
+    );
+}
@@ -0,0 +1,21 @@
+use remplate::Remplate;
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/minify.html", minify = true)]
+struct Minified {
+    title: &'static str,
+    code: &'static str,
+}
+
+#[test]
+fn render_minified_with_preformatted_block() {
+    let template = Minified {
+        title: "Main",
+        code: "run();",
+    };
+
+    assert_eq!(
+        template.render().unwrap(),
+        "<article> <h1>Main</h1> <pre>\n  fn main() {\n      run();\n  }\n</pre> </article> "
+    );
+}
@@ -0,0 +1,72 @@
+#![cfg(feature = "mmap")]
+
+use std::{
+    fmt,
+    fs::{self, File},
+};
+
+use remplate::Remplate;
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/counted_list.html")]
+struct CountedList {
+    count: usize,
+}
+
+#[test]
+fn render_to_mmap() {
+    let template = CountedList { count: 20_000 };
+    let path = std::env::temp_dir().join(format!("remplate_mmap_{}.html", std::process::id()));
+
+    let file = File::options()
+        .read(true)
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(&path)
+        .unwrap();
+    template.render_to_mmap(&file).unwrap();
+    drop(file);
+
+    let written = fs::read_to_string(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert!(written.len() > template.estimated_size());
+    assert_eq!(written, template.render().unwrap());
+}
+
+struct Failing;
+
+impl fmt::Display for Failing {
+    fn fmt(&self, _: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Err(fmt::Error)
+    }
+}
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/text.html")]
+struct FailingText {
+    text: Failing,
+}
+
+#[test]
+fn render_to_mmap_with_failing_display() {
+    let template = FailingText { text: Failing };
+    let path =
+        std::env::temp_dir().join(format!("remplate_mmap_failing_{}.html", std::process::id()));
+
+    let file = File::options()
+        .read(true)
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(&path)
+        .unwrap();
+    assert!(template.render_to_mmap(&file).is_err());
+    drop(file);
+
+    let written = fs::read_to_string(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(written, "<p>");
+}
@@ -0,0 +1,17 @@
+use remplate::Remplate;
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/multi_line.html")]
+struct MultiLine {
+    name: &'static str,
+}
+
+#[test]
+fn render_multi_line_code_block() {
+    let template = MultiLine { name: "World" };
+
+    assert_eq!(
+        template.render().unwrap(),
+        "<pre>Hello World\n{ \"banner\" }\n  spans lines</pre>\n"
+    );
+}
@@ -0,0 +1,17 @@
+#[no_implicit_prelude]
+mod without_prelude {
+    #[derive(::remplate::Remplate)]
+    #[remplate(path = "tests/templates/text.html")]
+    pub struct Text {
+        pub text: &'static str,
+    }
+}
+
+use remplate::Remplate;
+
+#[test]
+fn render_without_implicit_prelude() {
+    let template = without_prelude::Text { text: "Text" };
+
+    assert_eq!(template.render().unwrap(), "<p>Text</p>\n");
+}
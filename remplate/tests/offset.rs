@@ -0,0 +1,36 @@
+#![cfg(feature = "offset")]
+
+use remplate::Remplate;
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/offset.html")]
+struct Offset {
+    name: &'static str,
+}
+
+#[test]
+fn render_offset() {
+    let template = Offset { name: "remplate" };
+
+    assert_eq!(template.render().unwrap(), "HEADER:7;remplate;18\n");
+}
+
+#[test]
+fn render_offset_after_multi_byte_characters() {
+    let template = Offset { name: "äöü" };
+
+    assert_eq!(template.render().unwrap(), "HEADER:7;äöü;16\n");
+}
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/offset_include.html")]
+struct OffsetInclude {
+    name: &'static str,
+}
+
+#[test]
+fn render_offset_in_included_template() {
+    let template = OffsetInclude { name: "remplate" };
+
+    assert_eq!(template.render().unwrap(), "remplate@9");
+}
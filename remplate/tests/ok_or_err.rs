@@ -0,0 +1,26 @@
+use remplate::Remplate;
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/ok_or_err.html")]
+struct Status {
+    status: Result<u32, &'static str>,
+}
+
+#[test]
+fn render_ok_value() {
+    let template = Status { status: Ok(200) };
+
+    assert_eq!(template.render().unwrap(), "<p>200</p><p>200</p>\n");
+}
+
+#[test]
+fn render_err_value() {
+    let template = Status {
+        status: Err("<failed>"),
+    };
+
+    assert_eq!(
+        template.render().unwrap(),
+        "<p><failed></p><p>&lt;failed&gt;</p>\n"
+    );
+}
@@ -0,0 +1,34 @@
+use remplate::Remplate;
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/option.html")]
+struct OptionalFields {
+    title: &'static str,
+    subtitle: Option<String>,
+    count: Option<u32>,
+}
+
+#[test]
+fn render_some_values() {
+    let template = OptionalFields {
+        title: "Title",
+        subtitle: Some(String::from("<h2>Subtitle</h2>")),
+        count: Some(7),
+    };
+
+    assert_eq!(
+        template.render().unwrap(),
+        "<h1>Title</h1><h2>Subtitle</h2><p>  7</p>\n"
+    );
+}
+
+#[test]
+fn render_none_values() {
+    let template = OptionalFields {
+        title: "Title",
+        subtitle: None,
+        count: None,
+    };
+
+    assert_eq!(template.render().unwrap(), "<h1>Title</h1><p></p>\n");
+}
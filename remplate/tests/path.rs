@@ -0,0 +1,23 @@
+use std::path::{Path, PathBuf};
+
+use remplate::Remplate;
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/path.html")]
+struct Location {
+    file: PathBuf,
+    directory: &'static Path,
+}
+
+#[test]
+fn render_path_filter() {
+    let template = Location {
+        file: PathBuf::from("templates").join("page.html"),
+        directory: Path::new("/srv/www"),
+    };
+
+    assert_eq!(
+        template.render().unwrap(),
+        "<p>templates/page.html</p><p>/srv/www</p>\n"
+    );
+}
@@ -0,0 +1,14 @@
+use remplate::Remplate;
+
+#[derive(Remplate)]
+#[remplate(path_from = "REMPLATE_TEST_TEMPLATE_POINTER")]
+struct Pointed {
+    text: &'static str,
+}
+
+#[test]
+fn render_template_from_path_file() {
+    let template = Pointed { text: "Text" };
+
+    assert_eq!(template.render().unwrap(), "<p>Pointed Text</p>\n");
+}
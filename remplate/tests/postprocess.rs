@@ -0,0 +1,67 @@
+use remplate::Remplate;
+
+fn uppercase(rendered: String) -> String {
+    rendered.to_uppercase()
+}
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/text.html", postprocess = "uppercase")]
+struct Shouting {
+    text: &'static str,
+}
+
+#[derive(Remplate)]
+#[remplate(
+    path = "tests/templates/text.html",
+    postprocess = "uppercase",
+    runtime_escape = true
+)]
+struct ShoutingEscaped {
+    text: &'static str,
+}
+
+#[test]
+fn render_with_postprocess() {
+    let template = Shouting { text: "quiet" };
+
+    assert_eq!(template.render().unwrap(), "<P>QUIET</P>\n");
+    assert_eq!(template.to_string(), "<p>quiet</p>\n");
+}
+
+#[test]
+fn postprocess_every_rendered_string() {
+    let template = Shouting { text: "quiet" };
+    let expected = "<P>QUIET</P>\n";
+
+    assert_eq!(template.render_with_capacity(64).unwrap(), expected);
+    assert_eq!(&*template.render_arc().unwrap(), expected);
+
+    let mut appended = String::from("> ");
+    template.render_appending(&mut appended).unwrap();
+    assert_eq!(appended, format!("> {}", expected));
+
+    let mut written = Vec::new();
+    template.render_to_writer(&mut written).unwrap();
+    template.render_and_flush(&mut written).unwrap();
+    template.render_buffered(&mut written).unwrap();
+    assert_eq!(written, expected.repeat(3).into_bytes());
+
+    let mut limited = String::new();
+    template
+        .render_to_fmt_limited(&mut limited, expected.len())
+        .unwrap();
+    assert_eq!(limited, expected);
+
+    assert_eq!(
+        remplate::render_all([&template, &template]).unwrap(),
+        expected.repeat(2)
+    );
+}
+
+#[test]
+fn postprocess_generated_render_methods() {
+    let template = ShoutingEscaped { text: "<b>" };
+
+    assert_eq!(template.render_escaped(true).unwrap(), "<P>&LT;B&GT;</P>\n");
+    assert_eq!(template.render_escaped(false).unwrap(), "<P><B></P>\n");
+}
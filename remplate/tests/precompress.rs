@@ -0,0 +1,73 @@
+#![cfg(feature = "compression")]
+
+use std::io::Read;
+
+use remplate::Remplate;
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/large_partial.html", precompress = "gzip")]
+struct Footer;
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/large_partial.html", precompress = "deflate")]
+struct DeflatedFooter;
+
+#[derive(Remplate)]
+#[remplate(
+    path = "tests/templates/large_partial.html",
+    precompress = "gzip",
+    minify = true
+)]
+struct MinifiedFooter;
+
+#[test]
+fn decompress_gzip_template() {
+    let mut decompressed = String::new();
+    flate2::read::GzDecoder::new(Footer::COMPRESSED)
+        .read_to_string(&mut decompressed)
+        .unwrap();
+
+    assert_eq!(decompressed, Footer.render().unwrap());
+    assert!(Footer.compressed().len() < decompressed.len());
+}
+
+#[test]
+fn decompress_deflate_template() {
+    let mut decompressed = String::new();
+    flate2::read::DeflateDecoder::new(DeflatedFooter.compressed())
+        .read_to_string(&mut decompressed)
+        .unwrap();
+
+    assert_eq!(decompressed, DeflatedFooter.render().unwrap());
+}
+
+#[test]
+fn decompress_minified_template() {
+    let mut decompressed = String::new();
+    flate2::read::GzDecoder::new(MinifiedFooter.compressed())
+        .read_to_string(&mut decompressed)
+        .unwrap();
+
+    assert_eq!(decompressed, MinifiedFooter.render().unwrap());
+    assert_ne!(decompressed, Footer.render().unwrap());
+}
+
+#[derive(Remplate)]
+#[remplate(
+    path = "tests/templates/commented_partial.html",
+    precompress = "gzip",
+    trim_blocks = true,
+    lstrip_blocks = true
+)]
+struct CommentedFooter;
+
+#[test]
+fn decompress_template_with_comment() {
+    let mut decompressed = String::new();
+    flate2::read::GzDecoder::new(CommentedFooter.compressed())
+        .read_to_string(&mut decompressed)
+        .unwrap();
+
+    assert_eq!(decompressed, CommentedFooter.render().unwrap());
+    assert!(!decompressed.contains("static footer"));
+}
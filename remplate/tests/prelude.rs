@@ -0,0 +1,17 @@
+use remplate::prelude::*;
+
+#[derive(Template)]
+#[remplate(path = "tests/templates/text.html")]
+struct Text {
+    text: &'static str,
+}
+
+#[test]
+fn render_via_prelude() {
+    let templates = [Text { text: "a" }, Text { text: "b" }];
+
+    assert_eq!(
+        render_all(&templates).unwrap(),
+        templates[0].render().unwrap() + &templates[1].render().unwrap()
+    );
+}
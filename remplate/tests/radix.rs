@@ -0,0 +1,23 @@
+use remplate::Remplate;
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/radix.html")]
+struct Radix {
+    flags: u16,
+    byte: u8,
+    mode: u32,
+}
+
+#[test]
+fn render_radix_filters() {
+    let template = Radix {
+        flags: 0xab,
+        byte: 5,
+        mode: 0o755,
+    };
+
+    assert_eq!(
+        template.render().unwrap(),
+        "<p>00ab</p><p>00000101</p><p>755</p>\n"
+    );
+}
@@ -0,0 +1,86 @@
+use remplate::Remplate;
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/regions.html")]
+struct Page {
+    title: &'static str,
+    items: Vec<&'static str>,
+}
+
+#[test]
+fn render_header_region() {
+    let template = Page {
+        title: "Title",
+        items: vec!["a", "b"],
+    };
+
+    assert_eq!(
+        template.render_header().unwrap(),
+        "<header><h1>TITLE</h1></header>"
+    );
+}
+
+#[test]
+fn render_region_inside_loop() {
+    let template = Page {
+        title: "Title",
+        items: vec!["a", "b"],
+    };
+
+    assert_eq!(template.render_items().unwrap(), "<li>a</li><li>b</li>");
+}
+
+#[test]
+fn render_whole_template_with_regions() {
+    let template = Page {
+        title: "Title",
+        items: vec!["a", "b"],
+    };
+
+    assert_eq!(
+        template.render().unwrap(),
+        "\n<header><h1>TITLE</h1></header>\n<ul><li>a</li><li>b</li></ul>\n"
+    );
+}
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/region_info.html")]
+struct Article {
+    tags: Vec<&'static str>,
+    authors: Vec<&'static str>,
+}
+
+#[test]
+fn render_with_region_info_reports_empty_regions() {
+    let template = Article {
+        tags: vec![],
+        authors: vec!["Tom"],
+    };
+
+    let (rendered, region_info) = template.render_with_region_info().unwrap();
+
+    assert_eq!(rendered, template.render().unwrap());
+    assert_eq!(region_info.len(), 2);
+    assert!(!region_info["tags"]);
+    assert!(region_info["authors"]);
+}
+
+#[test]
+fn render_with_region_info_for_regions_inside_loops() {
+    let populated = Page {
+        title: "Title",
+        items: vec!["a"],
+    };
+    let empty = Page {
+        title: "Title",
+        items: vec![],
+    };
+
+    let (_, populated_info) = populated.render_with_region_info().unwrap();
+    let (_, empty_info) = empty.render_with_region_info().unwrap();
+
+    assert!(populated_info["header"]);
+    assert!(populated_info["items"]);
+    assert!(empty_info["header"]);
+    assert!(!empty_info["items"]);
+}
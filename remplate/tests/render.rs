@@ -0,0 +1,107 @@
+use remplate::Remplate;
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/text.html")]
+struct Text {
+    text: &'static str,
+}
+
+#[test]
+fn render_with_capacity() {
+    let template = Text { text: "Text" };
+
+    let rendered = template.render_with_capacity(4096).unwrap();
+
+    assert_eq!(rendered, template.render().unwrap());
+    assert!(rendered.capacity() >= 4096);
+}
+
+#[test]
+fn render_to_writer_and_buffered() {
+    let template = Text { text: "Text" };
+
+    let mut written = Vec::new();
+    template.render_to_writer(&mut written).unwrap();
+
+    let mut buffered = Vec::new();
+    template.render_buffered(&mut buffered).unwrap();
+
+    assert_eq!(written, template.render().unwrap().into_bytes());
+    assert_eq!(buffered, written);
+}
+
+#[derive(Default)]
+struct FlushRecorder {
+    written: Vec<u8>,
+    flush_count: usize,
+}
+
+impl std::io::Write for FlushRecorder {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.written.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.flush_count += 1;
+        Ok(())
+    }
+}
+
+#[test]
+fn render_to_writer_without_flushing() {
+    let template = Text { text: "Text" };
+
+    let mut writer = FlushRecorder::default();
+    template.render_to_writer(&mut writer).unwrap();
+    template.render_buffered(&mut writer).unwrap();
+
+    assert_eq!(writer.flush_count, 0);
+}
+
+#[test]
+fn render_and_flush() {
+    let template = Text { text: "Text" };
+
+    let mut writer = FlushRecorder::default();
+    template.render_and_flush(&mut writer).unwrap();
+
+    assert_eq!(writer.written, template.render().unwrap().into_bytes());
+    assert_eq!(writer.flush_count, 1);
+}
+
+#[test]
+fn render_arc() {
+    let template = Text { text: "Text" };
+
+    let rendered = template.render_arc().unwrap();
+
+    assert_eq!(&*rendered, template.render().unwrap());
+}
+
+#[test]
+fn render_appending() {
+    let first = Text { text: "First" };
+    let second = Text { text: "Second" };
+
+    let mut buf = String::new();
+    let first_count = first.render_appending(&mut buf).unwrap();
+    let second_count = second.render_appending(&mut buf).unwrap();
+
+    assert_eq!(first_count, first.render().unwrap().len());
+    assert_eq!(first_count + second_count, buf.len());
+    assert!(buf.ends_with(&second.render().unwrap()));
+}
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/text.html", inline_display = true)]
+struct InlineText {
+    text: &'static str,
+}
+
+#[test]
+fn render_with_inline_display() {
+    let template = InlineText { text: "Text" };
+
+    assert_eq!(template.to_string(), "<p>Text</p>\n");
+}
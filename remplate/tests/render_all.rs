@@ -0,0 +1,55 @@
+use remplate::Remplate;
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/row.html")]
+struct Row {
+    name: &'static str,
+    count: u32,
+}
+
+#[test]
+fn render_all_rows() {
+    let rows = vec![
+        Row {
+            name: "first",
+            count: 1,
+        },
+        Row {
+            name: "second",
+            count: 2,
+        },
+    ];
+
+    let expected = rows
+        .iter()
+        .map(|row| row.render().unwrap())
+        .collect::<String>();
+
+    assert_eq!(remplate::render_all(&rows).unwrap(), expected);
+}
+
+#[test]
+fn render_all_without_rows() {
+    let rows: Vec<Row> = Vec::new();
+
+    assert_eq!(remplate::render_all(&rows).unwrap(), "");
+}
+
+#[derive(Remplate)]
+#[remplate(
+    path = "tests/templates/text.html",
+    estimated_size_expr = "self.text.len() * 64"
+)]
+struct Paragraph {
+    text: &'static str,
+}
+
+#[test]
+fn reserve_runtime_estimated_sizes() {
+    let paragraphs = [Paragraph { text: "first" }, Paragraph { text: "second" }];
+
+    let rendered = remplate::render_all(&paragraphs).unwrap();
+
+    assert_eq!(rendered, "<p>first</p>\n<p>second</p>\n");
+    assert!(rendered.capacity() >= (5 + 6) * 64);
+}
@@ -0,0 +1,50 @@
+use std::fmt;
+
+use remplate::render_with;
+
+#[test]
+fn render_file_template_with_named_bindings() {
+    let user = ("Tom", 3);
+
+    let rendered = render_with!(
+        "tests/templates/render_with.html",
+        name = user.0,
+        count = user.1,
+    )
+    .unwrap();
+
+    assert_eq!(rendered, "<p>Tom has 3 new messages</p>\n");
+}
+
+#[test]
+fn render_file_template_with_expression_bindings() {
+    let messages = ["a"];
+
+    assert_eq!(
+        render_with!(
+            "tests/templates/render_with.html",
+            name = "Jerry".to_uppercase(),
+            count = messages.len()
+        )
+        .unwrap(),
+        "<p>JERRY has 1 new message</p>\n"
+    );
+}
+
+struct Failing;
+
+impl fmt::Display for Failing {
+    fn fmt(&self, _: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Err(fmt::Error)
+    }
+}
+
+#[test]
+fn render_file_template_with_failing_binding() {
+    assert!(render_with!(
+        "tests/templates/render_with.html",
+        name = Failing,
+        count = 1
+    )
+    .is_err());
+}
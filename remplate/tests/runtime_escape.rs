@@ -0,0 +1,21 @@
+use remplate::Remplate;
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/runtime_escape.html", runtime_escape = true)]
+struct RuntimeEscaped {
+    text: &'static str,
+}
+
+#[test]
+fn render_with_runtime_escaping() {
+    let template = RuntimeEscaped { text: "<b>'a'</b>" };
+
+    assert_eq!(
+        template.render_escaped(true).unwrap(),
+        "<p>&lt;b&gt;&#x27;a&#x27;&lt;/b&gt;</p><p>\\u003Cb\\u003E\\'a\\'\\u003C/b\\u003E</p>\n"
+    );
+    assert_eq!(
+        template.render_escaped(false).unwrap(),
+        template.render().unwrap()
+    );
+}
@@ -0,0 +1,30 @@
+use remplate::Remplate;
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/separated.html")]
+struct Separated {
+    items: Vec<&'static str>,
+}
+
+#[test]
+fn render_loop_with_separator() {
+    let template = Separated {
+        items: vec!["a", "b", "c"],
+    };
+
+    assert_eq!(template.render().unwrap(), "a, b, c");
+}
+
+#[test]
+fn render_loop_with_separator_for_single_item() {
+    let template = Separated { items: vec!["a"] };
+
+    assert_eq!(template.render().unwrap(), "a");
+}
+
+#[test]
+fn render_loop_with_separator_for_no_items() {
+    let template = Separated { items: vec![] };
+
+    assert_eq!(template.render().unwrap(), "");
+}
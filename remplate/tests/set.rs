@@ -0,0 +1,18 @@
+use remplate::Remplate;
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/set.html", trim_blocks = true)]
+struct Sum {
+    a: u32,
+    b: u32,
+}
+
+#[test]
+fn render_set_binding() {
+    let template = Sum { a: 4, b: 9 };
+
+    assert_eq!(
+        template.render().unwrap(),
+        "<p>4 + 9 = 13</p>\n<p>large</p>"
+    );
+}
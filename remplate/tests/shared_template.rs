@@ -0,0 +1,24 @@
+use remplate::Remplate;
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/text.html")]
+struct Heading {
+    text: &'static str,
+}
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/text.html")]
+struct Paragraph {
+    text: String,
+}
+
+#[test]
+fn render_structs_sharing_a_template() {
+    let heading = Heading { text: "Title" };
+    let paragraph = Paragraph {
+        text: "Title".to_string(),
+    };
+
+    assert_eq!(heading.render().unwrap(), paragraph.render().unwrap());
+    assert_eq!(Heading::ESTIMATED_SIZE, Paragraph::ESTIMATED_SIZE);
+}
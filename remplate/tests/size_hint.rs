@@ -0,0 +1,74 @@
+use remplate::Remplate;
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/size_hint_static.html")]
+struct Static;
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/size_hint_conditional.html")]
+struct Conditional {
+    is_bold: bool,
+}
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/list.html")]
+struct List {
+    items: Vec<&'static str>,
+}
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/text.html")]
+struct Text {
+    text: &'static str,
+}
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/size_hint_writing.html")]
+struct Writing {
+    xs: Vec<u32>,
+}
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/size_hint_binding.html")]
+struct Binding {
+    xs: Vec<u32>,
+}
+
+#[test]
+fn size_hint_of_static_template() {
+    assert_eq!(Static::SIZE_HINT, (16, Some(16)));
+    assert_eq!(Static.render().unwrap().len(), 16);
+}
+
+#[test]
+fn size_hint_of_conditional_template() {
+    assert_eq!(Conditional::SIZE_HINT, (18, Some(26)));
+    assert_eq!(Conditional { is_bold: false }.render().unwrap().len(), 18);
+    assert_eq!(Conditional { is_bold: true }.render().unwrap().len(), 26);
+}
+
+#[test]
+fn size_hint_of_looping_template() {
+    assert_eq!(List::SIZE_HINT, (10, None));
+    assert_eq!(List { items: vec![] }.render().unwrap().len(), 10);
+}
+
+#[test]
+fn size_hint_of_interpolating_template() {
+    assert_eq!(Text::SIZE_HINT, (8, None));
+    assert_eq!(Text { text: "" }.render().unwrap().len(), 8);
+}
+
+#[test]
+fn size_hint_of_writing_code_block() {
+    let template = Writing { xs: vec![1, 2] };
+
+    assert_eq!(Writing::SIZE_HINT, (10, None));
+    assert!(template.render().unwrap().len() > 10);
+}
+
+#[test]
+fn size_hint_of_binding_code_block() {
+    assert_eq!(Binding::SIZE_HINT, (8, Some(8)));
+    assert_eq!(Binding { xs: vec![1] }.render().unwrap().len(), 8);
+}
@@ -0,0 +1,23 @@
+use remplate::Remplate;
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/spec_arguments.html")]
+struct SpecArguments {
+    v: &'static str,
+    f: f64,
+    width: usize,
+}
+
+#[test]
+fn render_width_and_precision_from_bindings_and_expressions() {
+    let template = SpecArguments {
+        v: "ab",
+        f: 1.23456,
+        width: 4,
+    };
+
+    assert_eq!(
+        template.render().unwrap(),
+        "<p>    ab</p>\n<p>1.23</p>\n<p>  ab| 1.2  </p>\n"
+    );
+}
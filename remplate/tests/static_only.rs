@@ -0,0 +1,13 @@
+use remplate::Remplate;
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/static_only.html", static_only = true)]
+struct License;
+
+#[test]
+fn render_static_only_template() {
+    assert_eq!(
+        License.render().unwrap(),
+        "<h1>License</h1>\n<p>Permission is hereby granted {free of charge}.</p>\n"
+    );
+}
@@ -0,0 +1,14 @@
+use remplate::Remplate;
+
+#[derive(Remplate)]
+#[remplate(root = "REMPLATE_TEST_TEMPLATES_DIR", path = "page.html")]
+struct Page {
+    content: &'static str,
+}
+
+#[test]
+fn render_template_relative_to_root() {
+    let template = Page { content: "Content" };
+
+    assert_eq!(template.render().unwrap(), "<main>Content</main>\n");
+}
@@ -0,0 +1,41 @@
+use remplate::Remplate;
+
+#[derive(Remplate)]
+#[remplate(
+    path = "tests/templates/translated.html",
+    catalog = "tests/catalogs/messages.txt",
+    locale = "en"
+)]
+struct English {
+    name: &'static str,
+}
+
+#[derive(Remplate)]
+#[remplate(
+    path = "tests/templates/translated.html",
+    catalog = "tests/catalogs/messages.txt",
+    locale = "de"
+)]
+struct German {
+    name: &'static str,
+}
+
+#[test]
+fn render_english_messages() {
+    let template = English { name: "Tom" };
+
+    assert_eq!(
+        template.render().unwrap(),
+        "<h1>Hello, Tom!</h1>\n<p>See you soon</p>\n"
+    );
+}
+
+#[test]
+fn render_german_messages() {
+    let template = German { name: "Tom" };
+
+    assert_eq!(
+        template.render().unwrap(),
+        "<h1>Hallo, Tom!</h1>\n<p>Bis bald</p>\n"
+    );
+}
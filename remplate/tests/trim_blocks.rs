@@ -0,0 +1,37 @@
+use remplate::Remplate;
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/trim_blocks.html")]
+struct Untrimmed {
+    items: Vec<&'static str>,
+}
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/trim_blocks.html", trim_blocks = true)]
+struct Trimmed {
+    items: Vec<&'static str>,
+}
+
+#[test]
+fn render_without_trim_blocks() {
+    let template = Untrimmed {
+        items: vec!["a", "b"],
+    };
+
+    assert_eq!(
+        template.render().unwrap(),
+        "<ul>\n\n<li>a</li>\n\n<li>b</li>\n\n</ul>\n"
+    );
+}
+
+#[test]
+fn render_with_trim_blocks() {
+    let template = Trimmed {
+        items: vec!["a", "b"],
+    };
+
+    assert_eq!(
+        template.render().unwrap(),
+        "<ul>\n<li>a</li>\n<li>b</li>\n</ul>\n"
+    );
+}
@@ -0,0 +1,21 @@
+use remplate::Remplate;
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/truncate.html")]
+struct Preview {
+    body: &'static str,
+    title: String,
+}
+
+#[test]
+fn render_truncated_values() {
+    let template = Preview {
+        body: "Grüße aus Köln",
+        title: "Käse".to_string(),
+    };
+
+    assert_eq!(
+        template.render().unwrap(),
+        "<p>Grüße\u{2026}</p><p>Käse</p>\n"
+    );
+}
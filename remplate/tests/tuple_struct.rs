@@ -0,0 +1,15 @@
+use remplate::Remplate;
+
+#[derive(Remplate)]
+#[remplate(path = "tests/templates/tuple_row.html")]
+struct Row(String, u32);
+
+#[test]
+fn render_tuple_struct() {
+    let template = Row(String::from("name"), 7);
+
+    assert_eq!(
+        template.render().unwrap(),
+        "<td>name</td><td>7</td><td>007</td>\n"
+    );
+}
@@ -0,0 +1,12 @@
+struct Person;
+
+#[derive(remplate::Remplate)]
+#[remplate(
+    root = "REMPLATE_TEST_UI_DIR",
+    path = "static_only.html",
+    context = "Person",
+    precompress = "gzip"
+)]
+struct PersonTemplate;
+
+fn main() {}
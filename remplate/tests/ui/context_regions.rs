@@ -0,0 +1,13 @@
+struct Person {
+    name: &'static str,
+}
+
+#[derive(remplate::Remplate)]
+#[remplate(
+    root = "REMPLATE_TEST_UI_DIR",
+    path = "context_regions.html",
+    context = "Person"
+)]
+struct PersonTemplate;
+
+fn main() {}
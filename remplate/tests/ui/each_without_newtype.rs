@@ -0,0 +1,7 @@
+#[derive(remplate::Remplate)]
+#[remplate(root = "REMPLATE_TEST_UI_DIR", each = "literal_interpolation.html")]
+struct Rows {
+    rows: Vec<u32>,
+}
+
+fn main() {}
@@ -0,0 +1,4 @@
+fn main() {
+    let text = "Text";
+    let _ = remplate::remplate_inline!("<p>{ text </p>");
+}
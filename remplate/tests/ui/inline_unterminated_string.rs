@@ -0,0 +1,3 @@
+fn main() {
+    let _ = remplate::remplate_inline!(r#"<p>{ "unterminated }</p>"#);
+}
@@ -0,0 +1,7 @@
+#[derive(remplate::Remplate)]
+#[remplate(root = "REMPLATE_TEST_UI_DIR", path = "invalid_format_spec.html")]
+struct InvalidFormatSpec {
+    value: u32,
+}
+
+fn main() {}
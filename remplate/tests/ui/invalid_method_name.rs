@@ -0,0 +1,12 @@
+struct Person;
+
+#[derive(remplate::Remplate)]
+#[remplate(
+    root = "REMPLATE_TEST_UI_DIR",
+    path = "literal_interpolation.html",
+    context = "Person",
+    method = "to html"
+)]
+struct PersonTemplate;
+
+fn main() {}
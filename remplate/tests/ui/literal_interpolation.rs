@@ -0,0 +1,9 @@
+#![deny(deprecated)]
+
+#[derive(remplate::Remplate)]
+#[remplate(root = "REMPLATE_TEST_UI_DIR", path = "literal_interpolation.html", lint = true)]
+struct Linted {
+    value: u32,
+}
+
+fn main() {}
@@ -0,0 +1,4 @@
+#[derive(remplate::Remplate)]
+struct MissingAttribute;
+
+fn main() {}
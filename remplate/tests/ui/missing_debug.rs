@@ -0,0 +1,9 @@
+struct Widget;
+
+#[derive(remplate::Remplate)]
+#[remplate(root = "REMPLATE_TEST_UI_DIR", path = "missing_debug.html")]
+struct MissingDebug {
+    widget: Widget,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(remplate::Remplate)]
+#[remplate(root = "REMPLATE_TEST_UI_DIR", path = "missing_path_filter.html")]
+struct MissingPathFilter {
+    file: std::path::PathBuf,
+}
+
+fn main() {}
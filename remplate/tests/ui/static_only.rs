@@ -0,0 +1,5 @@
+#[derive(remplate::Remplate)]
+#[remplate(root = "REMPLATE_TEST_UI_DIR", path = "static_only.html", static_only = true)]
+struct License;
+
+fn main() {}
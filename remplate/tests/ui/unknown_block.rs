@@ -0,0 +1,7 @@
+#[derive(remplate::Remplate)]
+#[remplate(root = "REMPLATE_TEST_UI_DIR", path = "unknown_block.html")]
+struct UnknownBlock {
+    text: &'static str,
+}
+
+fn main() {}
@@ -0,0 +1,5 @@
+#[derive(remplate::Remplate)]
+#[remplate(root = "REMPLATE_TEST_UI_DIR", path = "unlexable_code_block.html")]
+struct Unlexable;
+
+fn main() {}
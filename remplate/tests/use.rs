@@ -0,0 +1,36 @@
+mod status {
+    use std::fmt::Display;
+
+    pub enum Status {
+        Active,
+        Archived,
+    }
+
+    impl Display for Status {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            match self {
+                Status::Active => f.write_str("active"),
+                Status::Archived => f.write_str("archived"),
+            }
+        }
+    }
+}
+
+mod page {
+    #[derive(remplate::Remplate)]
+    #[remplate(path = "tests/templates/use.html", use = "super::status::Status")]
+    pub struct Page {
+        pub status: super::status::Status,
+    }
+}
+
+#[test]
+fn render_with_injected_use() {
+    use remplate::Remplate;
+
+    let template = page::Page {
+        status: status::Status::Archived,
+    };
+
+    assert_eq!(template.render().unwrap(), "<p>active</p><p>archived</p>\n");
+}